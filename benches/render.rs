@@ -0,0 +1,83 @@
+//! Benchmarks for render path of progress bars.
+//!
+//! `refresh` group measures whole render path, including formatting of description, counter and stats.
+//! `animation` group measures only formatting of bar animation using `Animation::fmt_progress`.
+//!
+//! Compare against another revision using criterion baselines:
+//!
+//! ```text
+//! cargo bench --bench render -- --save-baseline before
+//! # checkout other revision
+//! cargo bench --bench render -- --baseline before
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kdam::{term::Renderer, tqdm, Animation, Bar, BarExt};
+
+/// Renderer which discards rendered text, so that only formatting is measured.
+#[derive(Debug)]
+struct Discard;
+
+impl Renderer for Discard {
+    fn render(&self, _pb: &Bar, text: &str) -> std::io::Result<()> {
+        black_box(text);
+        Ok(())
+    }
+}
+
+fn bench_refresh(c: &mut Criterion) {
+    let mut group = c.benchmark_group("refresh");
+
+    let bars = [
+        (
+            "plain",
+            tqdm!(total = 1_000_000, ncols = 40_i16, renderer = Discard),
+        ),
+        (
+            "coloured",
+            tqdm!(
+                total = 1_000_000,
+                ncols = 40_i16,
+                colour = "green",
+                desc = "coloured",
+                postfix = "loss=0.42",
+                renderer = Discard
+            ),
+        ),
+        ("indefinite", tqdm!(ncols = 40_i16, renderer = Discard)),
+    ];
+
+    for (name, mut pb) in bars {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                pb.update_to(black_box(500_000));
+                pb.refresh();
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_animation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("animation");
+
+    let animations = [
+        ("arrow", Animation::Arrow),
+        ("classic", Animation::Classic),
+        ("fillup", Animation::FillUp),
+        ("tqdm", Animation::Tqdm),
+        ("accessible", Animation::Tqdm.accessible()),
+    ];
+
+    for (name, animation) in animations {
+        group.bench_function(name, |b| {
+            b.iter(|| animation.fmt_progress(black_box(0.42), 80, "default"))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_refresh, bench_animation);
+criterion_main!(benches);
@@ -27,7 +27,7 @@ fn main() {
             .read_to_end(&mut chunk)
             .unwrap();
         let chunk_len = chunk.len();
-        dst_file.write(&chunk).unwrap();
+        dst_file.write_all(&chunk).unwrap();
         pb.update(chunk_len);
 
         if chunk_len == 0 {
@@ -0,0 +1,23 @@
+use kdam::{tqdm, BarExt, RowManager};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    RowManager::scoped(|scope| {
+        thread::scope(|s| {
+            for (i, total) in [150, 100, 200].into_iter().enumerate() {
+                s.spawn(move || {
+                    let mut pb =
+                        scope.spawn_bar(tqdm!(total = total, desc = format!("worker {}", i)));
+
+                    for _ in 0..total {
+                        thread::sleep(Duration::from_secs_f32(0.02));
+                        pb.update(1);
+                    }
+                });
+            }
+        });
+    });
+
+    println!("completed!");
+}
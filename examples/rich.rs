@@ -1,5 +1,5 @@
-use kdam::{tqdm, BarExt, Column, RichProgress};
 use kdam::term::Colorizer;
+use kdam::{tqdm, BarExt, Column, RichProgress};
 
 fn main() {
     let mut pb = RichProgress::new(
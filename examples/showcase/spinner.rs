@@ -1,9 +1,9 @@
 // [dependencies]
 // kdam = { version = "0.3.0", features = ["spinner"] }
 
-use std::io::Write;
 use kdam::term::get_columns_or;
 use kdam::Spinner;
+use std::io::Write;
 
 fn main() {
     let spin = Spinner::new(
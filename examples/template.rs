@@ -1,8 +1,8 @@
 // [dependencies]
 // kdam = { version = "0.3.0", features = ["spinner", "template"] }
 
-use kdam::{tqdm, BarExt};
 use kdam::Spinner;
+use kdam::{tqdm, BarExt};
 
 fn main() {
     let mut pb = tqdm!(
@@ -9,4 +9,13 @@ fn main() {
     pb.refresh();
 
     eprintln!();
+
+    let mut pb = tqdm!(bounce = true);
+
+    for _ in 0..10000000 {
+        pb.update(1);
+    }
+    pb.refresh();
+
+    eprintln!();
 }
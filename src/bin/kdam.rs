@@ -0,0 +1,81 @@
+//! Pipe progress tool, like `pv`.
+//!
+//! Copies stdin to stdout while displaying progress of bytes (or lines) in stderr.
+//!
+//! ```text
+//! cat big.iso | kdam --size 4700000000 > copy.iso
+//! tail -f access.log | kdam --lines --desc requests > /dev/null
+//! ```
+
+use kdam::{fs::BarReader, BarBuilder, BarExt};
+
+const USAGE: &str = "Usage: kdam [OPTIONS]
+
+Copy stdin to stdout while displaying progress in stderr.
+
+Options:
+  -d, --desc <TEXT>    Prefix for the progress bar
+  -l, --lines          Count lines instead of bytes
+  -s, --size <SIZE>    Expected total size in bytes (or lines)
+  -h, --help           Print help";
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut desc = None;
+    let mut lines = false;
+    let mut size = 0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-d" | "--desc" => desc = Some(value(&arg, args.next())),
+            "-l" | "--lines" => lines = true,
+            "-s" | "--size" => {
+                size = value(&arg, args.next())
+                    .parse()
+                    .unwrap_or_else(|_| exit(&format!("invalid value for '{}'", arg)))
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => exit(&format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    let mut builder = BarBuilder::default().total(size).dynamic_ncols(true);
+
+    if let Some(desc) = desc {
+        builder = builder.desc(desc);
+    }
+
+    builder = if lines {
+        builder.unit(" lines")
+    } else {
+        builder.unit("B").unit_scale(true).unit_divisor(1024)
+    };
+
+    let mut reader = BarReader::new(std::io::stdin().lock(), builder.build().unwrap());
+
+    if lines {
+        reader = reader.with_lines();
+    }
+
+    reader.pb.refresh();
+    let result = std::io::copy(&mut reader, &mut std::io::stdout().lock());
+    eprintln!();
+
+    if let Err(error) = result {
+        if error.kind() != std::io::ErrorKind::BrokenPipe {
+            exit(&error.to_string());
+        }
+    }
+}
+
+fn value(arg: &str, value: Option<String>) -> String {
+    value.unwrap_or_else(|| exit(&format!("a value is required for '{}'", arg)))
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    std::process::exit(2);
+}
@@ -0,0 +1,289 @@
+//! Migration layer for [indicatif](https://docs.rs/indicatif) style templates.
+//!
+//! [ProgressStyle](crate::compat::indicatif::ProgressStyle) accepts indicatif template strings
+//! and maps them onto [Bar](crate::Bar) template (`bar_format`) and options.
+//!
+//! | indicatif                          | kdam               |
+//! |------------------------------------|--------------------|
+//! | `{bar}`, `{wide_bar}`              | `{animation}`      |
+//! | `{pos}`, `{bytes}`, `{human_pos}`  | `{count}`          |
+//! | `{len}`, `{total_bytes}`, `{human_len}` | `{total}`     |
+//! | `{percent}`                        | `{percentage}`     |
+//! | `{elapsed}`                        | `{elapsed human=true}` |
+//! | `{elapsed_precise}`                | `{elapsed}`        |
+//! | `{eta}`                            | `{remaining human=true}` |
+//! | `{eta_precise}`                    | `{remaining}`      |
+//! | `{per_sec}`, `{bytes_per_sec}`     | `{rate}{unit}/s`   |
+//! | `{prefix}`                         | `{desc}`           |
+//! | `{msg}`, `{wide_msg}`              | `{postfix}`        |
+//! | `{spinner}`                        | `{spinner}`        |
+//!
+//! Widths and alignments are kept, styles are dropped except for the bar whose first style is used as bar colour.
+
+use crate::Animation;
+
+/// indicatif like progress style which can be applied to [BarBuilder](crate::BarBuilder).
+///
+/// # Example
+///
+/// ```
+/// use kdam::compat::indicatif::ProgressStyle;
+///
+/// let style = ProgressStyle::with_template(
+///     "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos:>7}/{len:7} {msg}"
+/// )
+/// .unwrap()
+/// .progress_chars("#>-");
+///
+/// assert_eq!(
+///     style.get_template(),
+///     "{spinner} [{elapsed}] [{animation}] {count:>7}/{total:7} {postfix}"
+/// );
+/// assert_eq!(style.get_ncols(), Some(40));
+/// assert_eq!(style.get_colour(), Some("cyan"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressStyle {
+    animation: Option<Animation>,
+    colour: Option<String>,
+    ncols: Option<i16>,
+    template: String,
+    tick_strings: Option<Vec<String>>,
+    unit_scale: bool,
+}
+
+impl ProgressStyle {
+    /// Create a new instance of [ProgressStyle](crate::compat::indicatif::ProgressStyle) from indicatif template.
+    /// Returns error if template contains unclosed braces or unknown keys.
+    pub fn with_template(template: &str) -> Result<Self, crate::Error> {
+        let mut style = Self {
+            animation: None,
+            colour: None,
+            ncols: None,
+            template: String::new(),
+            tick_strings: None,
+            unit_scale: false,
+        };
+
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    style.template += "{{";
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    style.template += "}}";
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => {
+                                return Err(crate::Error::InvalidTemplate(format!(
+                                    "unclosed placeholder '{{{}'",
+                                    placeholder
+                                )))
+                            }
+                        }
+                    }
+
+                    let mapped = style.map_placeholder(&placeholder)?;
+                    style.template += &mapped;
+                }
+                _ => style.template.push(c),
+            }
+        }
+
+        Ok(style)
+    }
+
+    fn map_placeholder(&mut self, placeholder: &str) -> Result<String, crate::Error> {
+        let (key, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+        let (align_width, styles) = spec.split_once('.').unwrap_or((spec, ""));
+        let format_spec = if align_width.is_empty() {
+            "".to_owned()
+        } else {
+            format!(":{}", align_width)
+        };
+
+        let mapped = match key {
+            "bar" | "wide_bar" => {
+                if key == "bar" {
+                    if let Ok(ncols) = align_width.parse::<i16>() {
+                        self.ncols = Some(ncols);
+                    }
+                }
+
+                if let Some(colour) = styles.split('/').next().filter(|x| !x.is_empty()) {
+                    self.colour = Some(colour.to_owned());
+                }
+
+                return Ok("{animation}".to_owned());
+            }
+            "spinner" => return Ok("{spinner}".to_owned()),
+            "pos" | "human_pos" => format!("{{count{}}}", format_spec),
+            "len" | "human_len" => format!("{{total{}}}", format_spec),
+            "bytes" | "binary_bytes" => {
+                self.unit_scale = true;
+                format!("{{count{}}}{{unit}}", format_spec)
+            }
+            "total_bytes" | "binary_total_bytes" => {
+                self.unit_scale = true;
+                format!("{{total{}}}{{unit}}", format_spec)
+            }
+            "percent" => format!(
+                "{{percentage{}}}",
+                if format_spec.is_empty() {
+                    ":.0"
+                } else {
+                    &format_spec
+                }
+            ),
+            "elapsed" => format!("{{elapsed{} human=true}}", format_spec),
+            "elapsed_precise" => format!("{{elapsed{}}}", format_spec),
+            "eta" => format!("{{remaining{} human=true}}", format_spec),
+            "eta_precise" => format!("{{remaining{}}}", format_spec),
+            "per_sec" => format!(
+                "{{rate{}}}{{unit}}/s",
+                if format_spec.is_empty() {
+                    ":.2"
+                } else {
+                    &format_spec
+                }
+            ),
+            "bytes_per_sec" | "binary_bytes_per_sec" => {
+                self.unit_scale = true;
+                format!(
+                    "{{rate{}}}{{unit}}/s",
+                    if format_spec.is_empty() {
+                        ":.2"
+                    } else {
+                        &format_spec
+                    }
+                )
+            }
+            "prefix" => format!("{{desc{} suffix=''}}", format_spec),
+            "msg" | "wide_msg" => format!("{{postfix{}}}", format_spec),
+            _ => {
+                return Err(crate::Error::InvalidTemplate(format!(
+                    "unknown indicatif template key '{}'",
+                    key
+                )))
+            }
+        };
+
+        Ok(mapped)
+    }
+
+    /// Set progress characters, like indicatif, first character is used for completed part,
+    /// last character for remaining part and characters in between for partially completed cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::compat::indicatif::ProgressStyle;
+    ///
+    /// let style = ProgressStyle::with_template("{bar}").unwrap().progress_chars("█▓▒░ ");
+    /// ```
+    pub fn progress_chars(mut self, chars: &str) -> Self {
+        let mut chars = chars
+            .chars()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>();
+
+        if chars.len() >= 2 {
+            let fill = chars.pop().unwrap();
+            chars.reverse();
+            self.animation = Some(Animation::CustomWithFill(chars, fill));
+        }
+
+        self
+    }
+
+    /// Set spinner frames, like indicatif, last frame is ignored as it is the finished state in indicatif.
+    pub fn tick_chars(mut self, chars: &str) -> Self {
+        let mut frames = chars
+            .chars()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>();
+        frames.pop();
+        self.tick_strings = Some(frames);
+        self
+    }
+
+    /// Set spinner frames, like indicatif, last frame is ignored as it is the finished state in indicatif.
+    pub fn tick_strings(mut self, strings: &[&str]) -> Self {
+        let mut frames = strings
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>();
+        frames.pop();
+        self.tick_strings = Some(frames);
+        self
+    }
+
+    /// Returns mapped kdam template.
+    pub fn get_template(&self) -> &str {
+        &self.template
+    }
+
+    /// Returns bar colour extracted from `{bar}` placeholder.
+    pub fn get_colour(&self) -> Option<&str> {
+        self.colour.as_deref()
+    }
+
+    /// Returns bar width extracted from `{bar}` placeholder.
+    pub fn get_ncols(&self) -> Option<i16> {
+        self.ncols
+    }
+
+    /// Apply style to [BarBuilder](crate::BarBuilder).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::compat::indicatif::ProgressStyle;
+    /// use kdam::BarBuilder;
+    ///
+    /// let style = ProgressStyle::with_template("{prefix} {bar:40} {pos}/{len}").unwrap();
+    /// let pb = style.apply(BarBuilder::default().total(100)).build().unwrap();
+    /// ```
+    #[cfg(feature = "template")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
+    pub fn apply(&self, builder: crate::BarBuilder) -> crate::BarBuilder {
+        let mut builder = builder.bar_format(self.template.as_str());
+
+        if let Some(animation) = &self.animation {
+            builder = builder.animation(animation.clone());
+        }
+
+        if let Some(colour) = &self.colour {
+            builder = builder.colour(colour.as_str());
+        }
+
+        if let Some(ncols) = self.ncols {
+            builder = builder.ncols(ncols);
+        }
+
+        if self.unit_scale {
+            builder = builder.unit_scale(true).unit("B").unit_divisor(1024);
+        }
+
+        #[cfg(feature = "spinner")]
+        if let Some(frames) = &self.tick_strings {
+            builder = builder.spinner(crate::Spinner::new(
+                &frames.iter().map(|x| x.as_str()).collect::<Vec<&str>>(),
+                100.0,
+                1.0,
+            ));
+        }
+
+        builder
+    }
+}
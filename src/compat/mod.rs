@@ -0,0 +1,3 @@
+//! Compatibility layers for migrating from other progress bar crates.
+
+pub mod indicatif;
@@ -0,0 +1,70 @@
+//! Error type of kdam.
+
+use alloc::string::String;
+use core::fmt;
+
+/// Errors returned by fallible operations of kdam.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use kdam::{tqdm, Error};
+///
+/// let mut pb = tqdm!();
+///
+/// match pb.set_colour("#zzzzzz") {
+///     Err(Error::InvalidColour(error)) => assert_eq!(error.colour(), "#zzzzzz"),
+///     _ => unreachable!(),
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// Template (bar format) could not be parsed or contains unknown placeholders.
+    InvalidTemplate(String),
+    /// Colour is not a primary colour, hex colour code, rgb(r,g,b) or gradient.
+    InvalidColour(crate::term::ColourError),
+    /// Charset of custom animation can't be rendered, see [Animation::validate](crate::Animation::validate).
+    InvalidCharset(String),
+    /// Writing to terminal failed.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTemplate(message) => write!(f, "invalid template: {}", message),
+            Self::InvalidColour(error) => write!(f, "{}", error),
+            Self::InvalidCharset(message) => write!(f, "invalid charset: {}", message),
+            #[cfg(feature = "std")]
+            Self::Io(error) => write!(f, "io error: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[cfg(feature = "template")]
+impl From<formatx::Error> for Error {
+    fn from(error: formatx::Error) -> Self {
+        Self::InvalidTemplate(error.message())
+    }
+}
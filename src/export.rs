@@ -0,0 +1,222 @@
+//! Export progress bars as SVG images and recorded timelines as CSV or JSON.
+//!
+//! CI jobs and documentation can embed progress summaries visually, no terminal is needed.
+//! Rendered SVG is returned as a string, which can be written to a file.
+//! History recorded using [Bar::with_history](crate::Bar::with_history) can be exported
+//! on finish for post-hoc analysis of throughput.
+//!
+//! # Example
+//!
+//! ```
+//! use kdam::{tqdm, BarExt};
+//!
+//! let mut pb = tqdm!(total = 100, desc = "build").with_history();
+//!
+//! for _ in 0..100 {
+//!     pb.update(1);
+//! }
+//!
+//! let snapshot = kdam::export::svg(&mut pb);
+//! let timeline = kdam::export::svg_timeline(&pb).unwrap();
+//!
+//! assert!(snapshot.starts_with("<svg"));
+//! assert!(timeline.contains("<polyline"));
+//!
+//! eprint!("\n");
+//! ```
+
+use crate::progress::{Bar, BarExt};
+use crate::term::Colorizer;
+
+const CHAR_WIDTH: f32 = 8.4;
+const FONT: &str = "font-family=\"monospace\" font-size=\"14\"";
+const PADDING: f32 = 10.0;
+const TIMELINE_HEIGHT: f32 = 160.0;
+const TIMELINE_WIDTH: f32 = 480.0;
+
+/// Render current state of progress bar as a terminal like SVG image.
+/// Colours and other ANSI escape sequences are removed from rendered text.
+pub fn svg<T: BarExt>(pb: &mut T) -> String {
+    let text = pb.render().trim_ansi();
+    let width = text.chars().count() as f32 * CHAR_WIDTH + 2.0 * PADDING;
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"34\" viewBox=\"0 0 {w} 34\">\
+<rect width=\"100%\" height=\"100%\" rx=\"4\" fill=\"#1e1e1e\"/>\
+<text x=\"{p}\" y=\"22\" fill=\"#d4d4d4\" {f} xml:space=\"preserve\">{t}</text>\
+</svg>\n",
+        w = width.ceil(),
+        p = PADDING,
+        f = FONT,
+        t = escape(&text)
+    )
+}
+
+/// Render recorded history of progress bar as a timeline of counter over elapsed time.
+/// Returns `None` if history is not enabled (see [Bar::with_history](crate::Bar::with_history)) or empty.
+pub fn svg_timeline(pb: &Bar) -> Option<String> {
+    let history = pb.get_history()?;
+    let first_time = history.iter().next()?.0;
+    let last_time = history.last()?.0;
+    let max_time = (last_time - first_time).max(f32::EPSILON);
+    let max_counter = if pb.indefinite() {
+        history.iter().map(|x| x.1).max().unwrap_or(0)
+    } else {
+        pb.get_total()
+    }
+    .max(1) as f32;
+
+    let points = history
+        .iter()
+        .map(|(time, counter, _)| {
+            format!(
+                "{:.1},{:.1}",
+                PADDING + (time - first_time) / max_time * TIMELINE_WIDTH,
+                PADDING + TIMELINE_HEIGHT - *counter as f32 / max_counter * TIMELINE_HEIGHT
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let caption = format!(
+        "{}{}{} in {}",
+        if pb.get_desc().is_empty() {
+            "".to_owned()
+        } else {
+            pb.get_desc().to_owned() + ": "
+        },
+        pb.fmt_counter(),
+        pb.get_unit(),
+        pb.fmt_elapsed_time()
+    );
+
+    let width = TIMELINE_WIDTH + 2.0 * PADDING;
+    let height = TIMELINE_HEIGHT + 2.0 * PADDING + 24.0;
+
+    Some(format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\
+<rect width=\"100%\" height=\"100%\" rx=\"4\" fill=\"#1e1e1e\"/>\
+<rect x=\"{p}\" y=\"{p}\" width=\"{tw}\" height=\"{th}\" fill=\"none\" stroke=\"#3c3c3c\"/>\
+<polyline points=\"{points}\" fill=\"none\" stroke=\"#4ec9b0\" stroke-width=\"2\"/>\
+<text x=\"{p}\" y=\"{y}\" fill=\"#d4d4d4\" {f} xml:space=\"preserve\">{t}</text>\
+</svg>\n",
+        w = width,
+        h = height,
+        p = PADDING,
+        tw = TIMELINE_WIDTH,
+        th = TIMELINE_HEIGHT,
+        points = points,
+        y = height - PADDING,
+        f = FONT,
+        t = escape(&caption)
+    ))
+}
+
+/// Export samples recorded using [Bar::with_history](crate::Bar::with_history) as CSV,
+/// with columns `elapsed,n,rate,eta`. Elapsed time and ETA are in seconds,
+/// ETA is left empty if bar is indefinite or rate is zero.
+/// Returns `None` if history is not enabled.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, MockClock};
+/// use std::time::Duration;
+///
+/// let clock = MockClock::default();
+/// let mut pb = tqdm!(total = 100, clock = clock.clone()).with_history_capacity(100);
+///
+/// for _ in 0..2 {
+///     clock.advance(Duration::from_secs(1));
+///     pb.update(25);
+/// }
+///
+/// assert_eq!(
+///     kdam::export::timeline_csv(&pb).unwrap(),
+///     "elapsed,n,rate,eta\n1.000,25,25.000,3.000\n2.000,50,25.000,2.000\n"
+/// );
+///
+/// eprint!("\n");
+/// ```
+pub fn timeline_csv(pb: &Bar) -> Option<String> {
+    let mut csv = "elapsed,n,rate,eta\n".to_owned();
+
+    for (elapsed_time, counter, rate, eta) in timeline(pb)? {
+        csv += &format!(
+            "{:.3},{},{:.3},{}\n",
+            elapsed_time,
+            counter,
+            rate,
+            eta.map(|x| format!("{:.3}", x)).unwrap_or_default()
+        );
+    }
+
+    Some(csv)
+}
+
+/// Export samples recorded using [Bar::with_history](crate::Bar::with_history) as a JSON array of objects,
+/// with same fields as [timeline_csv](crate::export::timeline_csv). ETA is `null` if it is unknown.
+/// Returns `None` if history is not enabled.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, MockClock};
+/// use std::time::Duration;
+///
+/// let clock = MockClock::default();
+/// let mut pb = tqdm!(clock = clock.clone()).with_history_capacity(100);
+///
+/// clock.advance(Duration::from_secs(2));
+/// pb.update(10);
+///
+/// assert_eq!(
+///     kdam::export::timeline_json(&pb).unwrap(),
+///     r#"[{"elapsed":2.000,"n":10,"rate":5.000,"eta":null}]"#
+/// );
+///
+/// eprint!("\n");
+/// ```
+pub fn timeline_json(pb: &Bar) -> Option<String> {
+    let samples = timeline(pb)?
+        .map(|(elapsed_time, counter, rate, eta)| {
+            format!(
+                "{{\"elapsed\":{:.3},\"n\":{},\"rate\":{:.3},\"eta\":{}}}",
+                elapsed_time,
+                counter,
+                rate,
+                eta.map(|x| format!("{:.3}", x))
+                    .unwrap_or_else(|| "null".to_owned())
+            )
+        })
+        .collect::<Vec<String>>();
+
+    Some(format!("[{}]", samples.join(",")))
+}
+
+/// Returns recorded samples of history along with ETA of each sample.
+fn timeline(pb: &Bar) -> Option<impl Iterator<Item = (f32, usize, f32, Option<f32>)> + '_> {
+    let total = pb.get_total();
+
+    Some(
+        pb.get_history()?
+            .iter()
+            .map(move |&(elapsed_time, counter, rate)| {
+                let eta = if total == 0 || rate <= 0.0 {
+                    None
+                } else {
+                    Some(total.saturating_sub(counter) as f32 / rate)
+                };
+
+                (elapsed_time, counter, rate, eta)
+            }),
+    )
+}
+
+/// Escape text for using it inside XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,479 @@
+//! Filesystem utilities driven by progress bars.
+
+use crate::progress::{Bar, BarBuilder, BarExt};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Recursively walk a directory and call `f` for every file found, displaying progress with `pb`.
+///
+/// Progress is displayed in two phases.
+/// In the first phase files are counted and `pb` is displayed as an indefinite bar.
+/// In the second phase total of `pb` is set to number of files, counter starts again from zero
+/// and `f` is called for each file. Other state of `pb` such as elapsed time is kept.
+/// Symbolic links to directories are not followed.
+///
+/// # Example
+///
+/// ```
+/// use kdam::tqdm;
+///
+/// let mut size = 0;
+///
+/// kdam::fs::walk_with_progress("src", tqdm!(unit = " files"), |path| {
+///     size += std::fs::metadata(path)?.len();
+///     Ok(())
+/// })
+/// .unwrap();
+///
+/// eprint!("\n");
+/// ```
+pub fn walk_with_progress<P, F>(root: P, mut pb: Bar, mut f: F) -> Result<(), std::io::Error>
+where
+    P: AsRef<Path>,
+    F: FnMut(&Path) -> Result<(), std::io::Error>,
+{
+    pb.set_total(0);
+    let mut files = vec![];
+    scan(root.as_ref(), &mut files, &mut pb)?;
+    pb.refresh();
+
+    pb.set_total(files.len());
+    pb.set_counter(0);
+    pb.refresh();
+
+    for file in files {
+        f(&file)?;
+        pb.update(1);
+    }
+
+    Ok(())
+}
+
+fn scan(path: &Path, files: &mut Vec<PathBuf>, pb: &mut Bar) -> Result<(), std::io::Error> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        pb.update(1);
+        return Ok(());
+    }
+
+    let mut entries = std::fs::read_dir(path)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|x| x.path());
+
+    for entry in entries {
+        if entry.file_type()?.is_dir() {
+            scan(&entry.path(), files, pb)?;
+        } else {
+            files.push(entry.path());
+            pb.update(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Copy a file or a directory tree from `src` to `dst`, displaying progress in bytes.
+/// Returns total number of bytes copied.
+///
+/// Total is computed by scanning metadata of all files before copying.
+/// Files are copied in chunks of `chunk_size` bytes.
+/// While copying a directory, overall progress is displayed at first row and progress of current file at second row.
+/// Symbolic links inside a directory are recreated as symbolic links on unix and skipped on other platforms.
+///
+/// # Example
+///
+/// ```
+/// let tmp = std::env::temp_dir().join("kdam_copy_with_progress");
+/// let _ = std::fs::remove_dir_all(&tmp);
+/// std::fs::create_dir_all(tmp.join("src").join("data")).unwrap();
+/// std::fs::write(tmp.join("src").join("data").join("file.txt"), "hello").unwrap();
+///
+/// #[cfg(unix)]
+/// std::os::unix::fs::symlink("data", tmp.join("src").join("link")).unwrap();
+///
+/// let bytes = kdam::fs::copy_with_progress(tmp.join("src"), tmp.join("dst"), 64 * 1024).unwrap();
+/// assert_eq!(bytes, 5);
+///
+/// #[cfg(unix)]
+/// assert_eq!(
+///     std::fs::read_link(tmp.join("dst").join("link")).unwrap(),
+///     std::path::Path::new("data")
+/// );
+///
+/// eprint!("\n");
+/// ```
+pub fn copy_with_progress<P, Q>(src: P, dst: Q, chunk_size: usize) -> Result<u64, std::io::Error>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let src = src.as_ref();
+    let dst = dst.as_ref();
+    let mut files = vec![];
+
+    if src.is_dir() {
+        let mut pb = Bar::default();
+        pb.set_disable(true);
+        scan(src, &mut files, &mut pb)?;
+    } else {
+        files.push(src.to_path_buf());
+    }
+
+    // Symbolic links inside a directory are not followed, they may point to directories.
+    let is_link = |file: &Path| -> Result<bool, std::io::Error> {
+        Ok(src.is_dir() && std::fs::symlink_metadata(file)?.file_type().is_symlink())
+    };
+
+    let mut total = 0;
+
+    for file in &files {
+        if !is_link(file)? {
+            total += std::fs::metadata(file)?.len();
+        }
+    }
+
+    let bytes_bar = |total: u64, position: u16, leave: bool| {
+        BarBuilder::default()
+            .total(total as usize)
+            .unit("B")
+            .unit_scale(true)
+            .unit_divisor(1024)
+            .position(position)
+            .leave(leave)
+            .build()
+            .unwrap()
+    };
+
+    let mut pb = bytes_bar(total, 0, true);
+    pb.refresh();
+
+    let mut buffer = vec![0; chunk_size.max(1)];
+
+    for file in files {
+        let dst_file = if src.is_dir() {
+            dst.join(file.strip_prefix(src).unwrap())
+        } else {
+            dst.to_path_buf()
+        };
+
+        if let Some(parent) = dst_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        if is_link(&file)? {
+            #[cfg(unix)]
+            {
+                let _ = std::fs::remove_file(&dst_file);
+                std::os::unix::fs::symlink(std::fs::read_link(&file)?, &dst_file)?;
+            }
+
+            continue;
+        }
+
+        let mut file_pb = None;
+
+        if src.is_dir() {
+            let mut fpb = bytes_bar(std::fs::metadata(&file)?.len(), 1, false);
+            fpb.set_description(file.strip_prefix(src).unwrap().to_string_lossy());
+            fpb.refresh();
+            file_pb = Some(fpb);
+        }
+
+        let mut reader = std::fs::File::open(&file)?;
+        let mut writer = std::fs::File::create(&dst_file)?;
+
+        loop {
+            let n = reader.read(&mut buffer)?;
+
+            if n == 0 {
+                break;
+            }
+
+            writer.write_all(&buffer[..n])?;
+            pb.update(n);
+
+            if let Some(fpb) = &mut file_pb {
+                fpb.update(n);
+            }
+        }
+
+        if let Some(mut fpb) = file_pb {
+            fpb.clear();
+        }
+    }
+
+    pb.refresh();
+    Ok(total)
+}
+
+/// Reader adapter which updates a progress bar with number of bytes (or lines) read from inner reader.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{fs::BarReader, tqdm};
+/// use std::io::Read;
+///
+/// let data = b"first\nsecond\nthird\n";
+/// let mut reader = BarReader::new(&data[..], tqdm!(total = data.len(), unit = "B"));
+/// let mut text = String::new();
+/// reader.read_to_string(&mut text).unwrap();
+///
+/// assert_eq!(reader.pb.get_counter(), data.len());
+///
+/// let mut reader = BarReader::new(&data[..], tqdm!(unit = " lines")).with_lines();
+/// std::io::copy(&mut reader, &mut std::io::sink()).unwrap();
+///
+/// assert_eq!(reader.pb.get_counter(), 3);
+/// eprint!("\n");
+/// ```
+#[derive(Debug)]
+pub struct BarReader<R> {
+    /// Reader to read data from.
+    pub reader: R,
+    /// Instance of [Bar](crate::Bar) to display progress updates.
+    pub pb: Bar,
+    lines: bool,
+}
+
+impl<R: Read> BarReader<R> {
+    /// Create a new instance of [BarReader](crate::fs::BarReader), which counts bytes read.
+    pub fn new(reader: R, pb: Bar) -> Self {
+        Self {
+            reader,
+            pb,
+            lines: false,
+        }
+    }
+
+    /// Count newlines instead of bytes read.
+    pub fn with_lines(mut self) -> Self {
+        self.lines = true;
+        self
+    }
+}
+
+impl<R: Read> Read for BarReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.reader.read(buf)?;
+
+        if n == 0 {
+            self.pb.refresh();
+        } else if self.lines {
+            self.pb
+                .update(buf[..n].iter().filter(|x| **x == b'\n').count());
+        } else {
+            self.pb.update(n);
+        }
+
+        Ok(n)
+    }
+}
+
+/// Progress counted by async adapters into an atomic counter, bar is rendered by a monitor task
+/// every `mininterval` so that rendering doesn't happen inside polls.
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+struct AsyncProgress {
+    counter: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    cancel: tokio_util::sync::CancellationToken,
+}
+
+#[cfg(feature = "tokio")]
+impl AsyncProgress {
+    /// Spawn monitor task for `pb`, must be called from within a tokio runtime.
+    fn new(pb: Bar) -> (std::sync::Arc<std::sync::Mutex<Bar>>, Self) {
+        let counter = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(pb.get_counter()));
+        let cancel = tokio_util::sync::CancellationToken::new();
+        let maxinterval = pb.get_mininterval();
+        let pb = std::sync::Arc::new(std::sync::Mutex::new(pb));
+        crate::monitor::counter_async(&pb, counter.clone(), maxinterval, cancel.clone());
+        (pb, Self { counter, cancel })
+    }
+
+    /// Count `n` bytes.
+    fn add(&self, n: usize) {
+        self.counter
+            .fetch_add(n, std::sync::atomic::Ordering::AcqRel);
+    }
+
+    /// Update bar with all counted bytes, bar is refreshed if `refresh` is `true`.
+    fn flush(&self, pb: &std::sync::Mutex<Bar>, refresh: bool) {
+        if let Ok(mut pb) = pb.lock() {
+            pb.set_counter(self.counter.load(std::sync::atomic::Ordering::Acquire));
+
+            if refresh {
+                pb.refresh();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Drop for AsyncProgress {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+/// Async reader adapter which counts number of bytes read from inner reader.
+///
+/// Progress bar is rendered by a monitor task every `mininterval` and once inner reader reaches end of file,
+/// so that polls never render it. Must be created from within a tokio runtime.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{fs::AsyncBarReader, tqdm};
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let data = vec![0_u8; 4096];
+///     let mut reader = AsyncBarReader::new(&data[..], tqdm!(total = data.len(), unit = "B"));
+///     tokio::io::copy(&mut reader, &mut tokio::io::sink()).await.unwrap();
+///
+///     assert_eq!(reader.pb.lock().unwrap().get_counter(), 4096);
+///     eprint!("\n");
+/// }
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+#[derive(Debug)]
+pub struct AsyncBarReader<R> {
+    /// Reader to read data from.
+    pub reader: R,
+    /// Instance of [Bar](crate::Bar) to display progress updates, shared with monitor task.
+    pub pb: std::sync::Arc<std::sync::Mutex<Bar>>,
+    progress: AsyncProgress,
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> AsyncBarReader<R> {
+    /// Create a new instance of [AsyncBarReader](crate::fs::AsyncBarReader).
+    pub fn new(reader: R, pb: Bar) -> Self {
+        let (pb, progress) = AsyncProgress::new(pb);
+        Self {
+            reader,
+            pb,
+            progress,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for AsyncBarReader<R> {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let filled = buf.filled().len();
+        let poll = std::pin::Pin::new(&mut this.reader).poll_read(cx, buf);
+
+        if let std::task::Poll::Ready(Ok(())) = poll {
+            let n = buf.filled().len() - filled;
+
+            if n == 0 {
+                this.progress.flush(&this.pb, true);
+            } else {
+                this.progress.add(n);
+            }
+        }
+
+        poll
+    }
+}
+
+/// Async writer adapter which counts number of bytes written to inner writer.
+///
+/// Progress bar is rendered by a monitor task every `mininterval` and when writer is shut down,
+/// so that polls never render it. Must be created from within a tokio runtime.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{fs::AsyncBarWriter, tqdm};
+/// use tokio::io::AsyncWriteExt;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let mut writer = AsyncBarWriter::new(tokio::io::sink(), tqdm!(unit = "B"));
+///
+///     for _ in 0..4 {
+///         writer.write_all(&[0; 1024]).await.unwrap();
+///     }
+///
+///     writer.flush().await.unwrap();
+///     assert_eq!(writer.pb.lock().unwrap().get_counter(), 4096);
+///     eprint!("\n");
+/// }
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+#[derive(Debug)]
+pub struct AsyncBarWriter<W> {
+    /// Writer to write data to.
+    pub writer: W,
+    /// Instance of [Bar](crate::Bar) to display progress updates, shared with monitor task.
+    pub pb: std::sync::Arc<std::sync::Mutex<Bar>>,
+    progress: AsyncProgress,
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin> AsyncBarWriter<W> {
+    /// Create a new instance of [AsyncBarWriter](crate::fs::AsyncBarWriter).
+    pub fn new(writer: W, pb: Bar) -> Self {
+        let (pb, progress) = AsyncProgress::new(pb);
+        Self {
+            writer,
+            pb,
+            progress,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for AsyncBarWriter<W> {
+    fn poll_write(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let poll = std::pin::Pin::new(&mut this.writer).poll_write(cx, buf);
+
+        if let std::task::Poll::Ready(Ok(n)) = poll {
+            this.progress.add(n);
+        }
+
+        poll
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let poll = std::pin::Pin::new(&mut this.writer).poll_flush(cx);
+
+        if poll.is_ready() {
+            this.progress.flush(&this.pb, false);
+        }
+
+        poll
+    }
+
+    fn poll_shutdown(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let poll = std::pin::Pin::new(&mut this.writer).poll_shutdown(cx);
+
+        if poll.is_ready() {
+            this.progress.flush(&this.pb, true);
+        }
+
+        poll
+    }
+}
@@ -0,0 +1,129 @@
+//! Hand off state of a progress bar to a new process, across `exec` or re-spawn.
+//!
+//! Self updating CLIs and wrapper scripts can keep a continuous bar, by serializing state of bar into
+//! an environment variable ([ENV_VAR](crate::handoff::ENV_VAR)) or a file before starting new process.
+//! New process resumes its bar with same counter, total, description and elapsed time,
+//! so that rate and remaining time also continue from where they were left.
+//!
+//! State is serialized as a single line `<counter> <total> <elapsed time> <description>`.
+//!
+//! # Example
+//!
+//! ```
+//! use kdam::{tqdm, BarExt};
+//!
+//! let mut pb = tqdm!(total = 100, desc = "update");
+//! pb.update(40);
+//! let state = kdam::handoff::encode(&pb);
+//!
+//! // In new process.
+//! let mut pb = tqdm!(total = 100);
+//! assert!(kdam::handoff::decode(&state, &mut pb));
+//! assert_eq!(pb.get_counter(), 40);
+//! assert_eq!(pb.get_desc(), "update");
+//!
+//! eprint!("\n");
+//! ```
+//!
+//! Re-spawning current executable with state passed in an environment variable.
+//!
+//! ```no_run
+//! use kdam::{tqdm, BarExt};
+//! use std::process::Command;
+//!
+//! let mut pb = tqdm!(total = 100, desc = "install");
+//!
+//! if kdam::handoff::resume(&mut pb) {
+//!     // Second stage, bar continues from 50.
+//!     pb.update(50);
+//! } else {
+//!     pb.update(50);
+//!     Command::new(std::env::current_exe().unwrap())
+//!         .env(kdam::handoff::ENV_VAR, kdam::handoff::encode(&pb))
+//!         .status()
+//!         .unwrap();
+//! }
+//! ```
+
+use crate::progress::Bar;
+use std::path::Path;
+use std::time::Duration;
+
+/// Environment variable which holds state of bar, see [resume](crate::handoff::resume).
+pub const ENV_VAR: &str = "KDAM_HANDOFF";
+
+/// Returns serialized state of bar, which can be resumed using [decode](crate::handoff::decode).
+pub fn encode(pb: &Bar) -> String {
+    format!(
+        "{} {} {} {}",
+        pb.get_counter(),
+        pb.get_total(),
+        pb.clock_elapsed().as_secs_f32(),
+        pb.get_desc().replace(['\n', '\r'], " ")
+    )
+}
+
+/// Resume bar from serialized `state`, returns wheter `state` is valid or not.
+/// Description of bar is only replaced if `state` has a non empty description.
+pub fn decode(state: &str, pb: &mut Bar) -> bool {
+    let mut parts = state.trim_end_matches(['\n', '\r']).splitn(4, ' ');
+
+    let (Some(Ok(counter)), Some(Ok(total)), Some(Ok(elapsed_time))) = (
+        parts.next().map(|x| x.parse::<usize>()),
+        parts.next().map(|x| x.parse::<usize>()),
+        parts.next().map(|x| x.parse::<f32>()),
+    ) else {
+        return false;
+    };
+
+    if !elapsed_time.is_finite() || elapsed_time < 0.0 {
+        return false;
+    }
+
+    if let Some(desc) = parts.next().filter(|x| !x.is_empty()) {
+        pb.set_description(desc.to_owned());
+    }
+
+    pb.set_total(total);
+    pb.set_counter(counter);
+    pb.resume_elapsed(Duration::from_secs_f32(elapsed_time));
+    true
+}
+
+/// Resume bar from state stored in [ENV_VAR](crate::handoff::ENV_VAR) environment variable,
+/// returns wheter bar is resumed or not.
+pub fn resume(pb: &mut Bar) -> bool {
+    std::env::var(ENV_VAR).is_ok_and(|state| decode(&state, pb))
+}
+
+/// Write serialized state of bar to file at `path`, see [load](crate::handoff::load).
+pub fn save<P: AsRef<Path>>(pb: &Bar, path: P) -> std::io::Result<()> {
+    std::fs::write(path, encode(pb) + "\n")
+}
+
+/// Resume bar from state stored in file at `path`, returns wheter bar is resumed or not.
+/// File is removed after it is read, so that a stale state isn't resumed again.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+///
+/// let path = std::env::temp_dir().join("kdam-handoff-example");
+///
+/// let mut pb = tqdm!(total = 10);
+/// pb.update(4);
+/// kdam::handoff::save(&pb, &path).unwrap();
+///
+/// let mut pb = tqdm!(total = 10);
+/// assert!(kdam::handoff::load(&path, &mut pb).unwrap());
+/// assert_eq!(pb.get_counter(), 4);
+/// assert!(!path.exists());
+///
+/// eprint!("\n");
+/// ```
+pub fn load<P: AsRef<Path>>(path: P, pb: &mut Bar) -> std::io::Result<bool> {
+    let state = std::fs::read_to_string(&path)?;
+    std::fs::remove_file(path)?;
+    Ok(decode(&state, pb))
+}
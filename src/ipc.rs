@@ -0,0 +1,356 @@
+//! Progress reporting over IPC for child processes.
+//!
+//! Parent process hosts progress bars using [Host](crate::ipc::Host), while child processes send updates
+//! using [Reporter](crate::ipc::Reporter) over a pipe or an unix socket. This way build tools spawning
+//! workers can display unified progress bars.
+//!
+//! Updates are sent as newline separated messages of a tiny text protocol.
+//!
+//! | Message           | Description                          |
+//! |-------------------|--------------------------------------|
+//! | `update <n>`      | increment counter by `n`             |
+//! | `update_to <n>`   | set counter to `n`                   |
+//! | `total <n>`       | set total to `n`                     |
+//! | `desc <text>`     | set description                      |
+//! | `postfix <text>`  | set postfix                          |
+//! | `close`           | progress is finished                 |
+//!
+//! # Example
+//!
+//! ```
+//! use kdam::ipc::{Host, Reporter};
+//! use kdam::{tqdm, RowManager};
+//!
+//! // In child process, messages are usually written to a pipe or an unix socket.
+//! let mut reporter = Reporter::new(vec![]);
+//! reporter.set_total(3).unwrap();
+//! reporter.update(3).unwrap();
+//! reporter.close().unwrap();
+//!
+//! let mut host = Host::new(RowManager::new(3));
+//! host.attach(std::io::Cursor::new(reporter.into_inner()), tqdm!());
+//! let manager = host.join();
+//!
+//! assert_eq!(manager.bars[0].get_counter(), 3);
+//! ```
+
+use crate::progress::{Bar, BarExt};
+use crate::RowManager;
+use std::io::{BufRead, BufReader, Read, Write};
+#[cfg(unix)]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Environment variable which holds socket path of host, see [Host::listen](crate::ipc::Host::listen).
+pub const ENV_VAR: &str = "KDAM_IPC";
+
+/// Messages of IPC protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// Set description.
+    Desc(String),
+    /// Set postfix.
+    Postfix(String),
+    /// Set total.
+    Total(usize),
+    /// Increment counter.
+    Update(usize),
+    /// Set counter.
+    UpdateTo(usize),
+    /// Progress is finished.
+    Close,
+}
+
+impl Message {
+    /// Parse a message from a single line, returns `None` for unknown or malformed messages.
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        let (name, value) = line.split_once(' ').unwrap_or((line, ""));
+
+        match name {
+            "desc" => Some(Self::Desc(value.to_owned())),
+            "postfix" => Some(Self::Postfix(value.to_owned())),
+            "total" => value.parse().ok().map(Self::Total),
+            "update" => value.parse().ok().map(Self::Update),
+            "update_to" => value.parse().ok().map(Self::UpdateTo),
+            "close" => Some(Self::Close),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Desc(desc) => write!(f, "desc {}", desc.replace(['\r', '\n'], " ")),
+            Self::Postfix(postfix) => write!(f, "postfix {}", postfix.replace(['\r', '\n'], " ")),
+            Self::Total(total) => write!(f, "total {}", total),
+            Self::Update(n) => write!(f, "update {}", n),
+            Self::UpdateTo(n) => write!(f, "update_to {}", n),
+            Self::Close => write!(f, "close"),
+        }
+    }
+}
+
+/// Sends progress updates to [Host](crate::ipc::Host) from a child process.
+#[derive(Debug)]
+pub struct Reporter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Reporter<W> {
+    /// Create a new reporter which writes messages to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Send a message to host.
+    pub fn send(&mut self, message: &Message) -> std::io::Result<()> {
+        writeln!(self.writer, "{}", message)?;
+        self.writer.flush()
+    }
+
+    /// Set description of progress bar.
+    pub fn set_description<T: Into<String>>(&mut self, desc: T) -> std::io::Result<()> {
+        self.send(&Message::Desc(desc.into()))
+    }
+
+    /// Set postfix of progress bar.
+    pub fn set_postfix<T: Into<String>>(&mut self, postfix: T) -> std::io::Result<()> {
+        self.send(&Message::Postfix(postfix.into()))
+    }
+
+    /// Set total of progress bar.
+    pub fn set_total(&mut self, total: usize) -> std::io::Result<()> {
+        self.send(&Message::Total(total))
+    }
+
+    /// Increment counter of progress bar.
+    pub fn update(&mut self, n: usize) -> std::io::Result<()> {
+        self.send(&Message::Update(n))
+    }
+
+    /// Set counter of progress bar.
+    pub fn update_to(&mut self, n: usize) -> std::io::Result<()> {
+        self.send(&Message::UpdateTo(n))
+    }
+
+    /// Notify host that progress is finished.
+    pub fn close(&mut self) -> std::io::Result<()> {
+        self.send(&Message::Close)
+    }
+
+    /// Returns underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(unix)]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+impl Reporter<std::os::unix::net::UnixStream> {
+    /// Connect to host listening at unix socket `path`.
+    pub fn connect<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        Ok(Self::new(std::os::unix::net::UnixStream::connect(path)?))
+    }
+
+    /// Connect to host using socket path from [ENV_VAR](crate::ipc::ENV_VAR).
+    /// Returns `None` if environment variable is not set, i.e. process is not spawned by a host.
+    pub fn from_env() -> Option<std::io::Result<Self>> {
+        std::env::var_os(ENV_VAR).map(Self::connect)
+    }
+}
+
+/// Hosts progress bars of child processes in parent process.
+///
+/// Every attached connection is displayed as a separate progress bar managed by a [RowManager](crate::RowManager).
+pub struct Host {
+    handles: Arc<Mutex<Vec<thread::JoinHandle<()>>>>,
+    manager: Arc<Mutex<RowManager>>,
+}
+
+impl Host {
+    /// Create a new host which displays progress bars using `manager`.
+    pub fn new(manager: RowManager) -> Self {
+        Self {
+            handles: Arc::new(Mutex::new(vec![])),
+            manager: Arc::new(Mutex::new(manager)),
+        }
+    }
+
+    /// Returns shared progress bars manager of host.
+    pub fn manager(&self) -> Arc<Mutex<RowManager>> {
+        self.manager.clone()
+    }
+
+    /// Read messages from `reader` (e.g. stdout of a child process) and display them using `pb`.
+    /// Messages are read in a separate thread, returns index of progress bar in manager.
+    pub fn attach<R: Read + Send + 'static>(&mut self, reader: R, pb: Bar) -> usize {
+        attach(&self.manager, &self.handles, reader, pb)
+    }
+
+    /// Listen for reporters at unix socket `path` in a separate thread, every connection is attached with a new progress bar.
+    /// Set [ENV_VAR](crate::ipc::ENV_VAR) to `path` for child processes, so they can use [Reporter::from_env](crate::ipc::Reporter::from_env).
+    ///
+    /// Returned handle stops listening, see [ListenHandle](crate::ipc::ListenHandle).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::ipc::{Host, Reporter};
+    /// use kdam::RowManager;
+    ///
+    /// let path = std::env::temp_dir().join(format!("kdam-{}.sock", std::process::id()));
+    /// let mut host = Host::new(RowManager::new(3));
+    /// let listener = host.listen(&path).unwrap();
+    ///
+    /// let mut reporter = Reporter::connect(&path).unwrap();
+    /// reporter.update(3).unwrap();
+    /// reporter.close().unwrap();
+    ///
+    /// listener.stop();
+    /// listener.join();
+    /// std::fs::remove_file(&path).unwrap();
+    ///
+    /// let manager = host.join();
+    /// assert_eq!(manager.bars[0].get_counter(), 3);
+    /// ```
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn listen<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<ListenHandle> {
+        use std::os::unix::io::AsRawFd;
+
+        let listener = std::os::unix::net::UnixListener::bind(path)?;
+        let manager = self.manager.clone();
+        let handles = self.handles.clone();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stopped_clone = stopped.clone();
+
+        let handle = thread::spawn(move || {
+            while !stopped_clone.load(Ordering::Acquire) {
+                // Listener is polled with a timeout, so that stop flag is checked while waiting for connections.
+                let mut fd = libc::pollfd {
+                    fd: listener.as_raw_fd(),
+                    events: libc::POLLIN,
+                    revents: 0,
+                };
+
+                // SAFETY: `fd` is a single valid `pollfd` living on stack for whole call, so `nfds` is 1,
+                // and its file descriptor is owned by `listener` which outlives the call.
+                if unsafe { libc::poll(&mut fd, 1, 100) } <= 0 {
+                    continue;
+                }
+
+                if let Ok((stream, _)) = listener.accept() {
+                    attach(&manager, &handles, stream, Bar::new(0));
+                }
+            }
+
+            // Connections made before stopping are still attached.
+            if listener.set_nonblocking(true).is_ok() {
+                while let Ok((stream, _)) = listener.accept() {
+                    if stream.set_nonblocking(false).is_ok() {
+                        attach(&manager, &handles, stream, Bar::new(0));
+                    }
+                }
+            }
+        });
+
+        Ok(ListenHandle { handle, stopped })
+    }
+
+    /// Wait for all attached connections to be closed, returns manager of progress bars.
+    pub fn join(self) -> RowManager {
+        loop {
+            let handle = self.handles.lock().unwrap().pop();
+
+            match handle {
+                Some(handle) => {
+                    let _ = handle.join();
+                }
+                None => break,
+            }
+        }
+
+        match Arc::try_unwrap(self.manager) {
+            Ok(manager) => manager.into_inner().unwrap(),
+            Err(manager) => std::mem::replace(&mut *manager.lock().unwrap(), RowManager::new(0)),
+        }
+    }
+}
+
+/// Handle of listener thread, returned by [Host::listen](crate::ipc::Host::listen).
+///
+/// Listener thread accepts connections until [stop](Self::stop) is called, already attached connections
+/// are read until they are closed, see [Host::join](crate::ipc::Host::join). Dropping handle detaches listener thread.
+#[cfg(unix)]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+#[derive(Debug)]
+pub struct ListenHandle {
+    handle: thread::JoinHandle<()>,
+    stopped: Arc<AtomicBool>,
+}
+
+#[cfg(unix)]
+impl ListenHandle {
+    /// Stop accepting new connections without waiting for listener thread to exit.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+
+    /// Returns wheter listener thread is finished or not.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Wait for listener thread to exit, it exits shortly after [stop](Self::stop) is called.
+    pub fn join(self) {
+        let _ = self.handle.join();
+    }
+}
+
+fn attach<R: Read + Send + 'static>(
+    manager: &Arc<Mutex<RowManager>>,
+    handles: &Arc<Mutex<Vec<thread::JoinHandle<()>>>>,
+    reader: R,
+    pb: Bar,
+) -> usize {
+    let index = manager.lock().unwrap().append(pb);
+    let manager = manager.clone();
+
+    let handle = thread::spawn(move || {
+        for line in BufReader::new(reader).lines() {
+            let message = match line {
+                Ok(line) => Message::parse(&line),
+                Err(_) => break,
+            };
+
+            let mut manager = manager.lock().unwrap();
+            let pb = manager.get_mut(index).unwrap();
+
+            match message {
+                Some(Message::Desc(desc)) => pb.set_description(desc),
+                Some(Message::Postfix(postfix)) => pb.set_postfix(postfix),
+                Some(Message::Total(total)) => pb.set_total(total),
+                Some(Message::Update(n)) => {
+                    pb.update(n);
+                }
+                Some(Message::UpdateTo(n)) => {
+                    pb.update_to(n);
+                }
+                Some(Message::Close) => break,
+                None => continue,
+            }
+
+            manager.notify(index);
+        }
+
+        let mut manager = manager.lock().unwrap();
+        manager.get_mut(index).unwrap().refresh();
+        manager.notify(index);
+    });
+
+    handles.lock().unwrap().push(handle);
+    index
+}
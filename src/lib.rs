@@ -1,4 +1,5 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 // #![doc=include_str!("../README.md")]
 
 //! Ultimate console progress bar for Rust.
@@ -16,6 +17,8 @@
 //! - [Project Examples](https://github.com/clitic/kdam/tree/main/examples)
 //!
 //! ```
+//! # #[cfg(feature = "std")]
+//! # {
 //! use kdam::{tqdm, BarExt};
 //!
 //! let mut pb = tqdm!(total = 100);
@@ -25,27 +28,86 @@
 //! }
 //!
 //! eprint!("\n");
+//! # }
 //! ```
 //!
 //! ## Cargo Features
-//! 
+//!
+//! - **bin**: Builds `kdam` executable, a pipe progress tool (like `pv`) which copies stdin to stdout while displaying progress.
+//! - **crossbeam**: Enables progress bars for `crossbeam_channel` receivers with queue depth using [TqdmReceiver](crate::TqdmReceiver).
 //! - **gradient**: Enables gradient colours for progress bars and printing text.
-//! - **spinner**: Enables support for using spinners. 
+//! - **notify**: Enables desktop notifications on completion using [Notify::Desktop](crate::Notify::Desktop).
+//! - **opentelemetry**: Enables exporting progress as OpenTelemetry metrics using [otel](crate::otel) module.
+//! - **serde**: Enables loading progress bar options from config files using [BarConfig](crate::BarConfig).
+//! - **signal**: Enables finalizing progress bars on Ctrl+C using [interrupt](crate::interrupt) module (unix only).
+//! - **spinner**: Enables support for using spinners.
+//! - **spinners**: Enables [spinners](crate::spinners) data set of ready to use spinners, see [Spinner::known](crate::Spinner::known).
+//! - **std** (default): Enables progress bars and terminal backends. Without it, crate is `no_std` (with `alloc`)
+//!   and only provides rendering core i.e. [format](crate::format) functions, [Animation](crate::Animation)
+//!   and [colours](crate::term::Colorizer), so progress strings can be shipped over RTT/semihosting on embedded targets.
+//! - **sysinfo**: Enables CPU and memory usage columns of current process.
 //! - **template**: Enables templating capabilities for [Bar](crate::Bar).
+//! - **tokio**: Enables monitor mode using tokio tasks with cancellation tokens, see [monitor::bar_async](crate::monitor::bar_async),
+//!   and async IO adapters [AsyncBarReader](crate::fs::AsyncBarReader) and [AsyncBarWriter](crate::fs::AsyncBarWriter).
+//! - **wasm**: Enables support for `wasm32-unknown-unknown` target and rendering progress bars in browser console using [ConsoleRenderer](crate::term::ConsoleRenderer).
 //! - **writer**: Enables redirecting progress bar output to a writer using [BarExt](crate::BarExt) trait.
 
+extern crate alloc;
+
+mod error;
+#[cfg(feature = "std")]
 mod progress;
-mod styles;
+#[cfg(feature = "std")]
 mod thread;
 
+#[cfg(feature = "std")]
+pub mod compat;
+#[cfg(feature = "std")]
+pub mod export;
+#[cfg(feature = "std")]
+pub mod fs;
+#[cfg(feature = "std")]
+pub mod handoff;
+#[cfg(feature = "std")]
+pub mod ipc;
+#[cfg(feature = "std")]
+pub mod ml;
+#[cfg(feature = "opentelemetry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opentelemetry")))]
+pub mod otel;
+pub mod styles;
 pub mod term;
+#[cfg(feature = "std")]
+pub mod testing;
 
+pub use error::Error;
 pub use styles::format;
-pub use thread::monitor;
+#[cfg(feature = "spinners")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spinners")))]
+pub use styles::spinners;
+#[cfg(all(unix, feature = "signal"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "signal"))))]
+pub use thread::interrupt;
+#[cfg(feature = "std")]
+pub use thread::{monitor, registry, ticker};
 
-pub use progress::{Bar, BarBuilder, BarExt, BarIterator, Column, RichProgress, TqdmIterator};
+#[cfg(feature = "std")]
+pub use progress::{
+    get_defaults, is_enabled, set_defaults, set_enabled, Bar, BarBuilder, BarExt, BarHelpers,
+    BarIterator, BarState, Channel, Clock, Column, Defaults, Eta, FBar, Latency, LayoutPriority,
+    MockClock, Notify, OnComplete, Overflow, ReceiverIterator, RichProgress, Secondary,
+    SystemClock, Timeline, TqdmIterator, TqdmReceiver,
+};
 pub use styles::Animation;
-pub use thread::RowManager;
+#[cfg(feature = "std")]
+pub use thread::{Dashboard, DashboardSort, RowManager, RowScope, ScopedBar};
 
+#[cfg(feature = "serde")]
+pub use progress::BarConfig;
 #[cfg(feature = "spinner")]
 pub use styles::Spinner;
+
+#[cfg(all(feature = "std", not(feature = "wasm")))]
+pub(crate) use std::time::Instant;
+#[cfg(feature = "wasm")]
+pub(crate) use web_time::Instant;
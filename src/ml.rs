@@ -0,0 +1,129 @@
+//! Helpers for machine learning training loops.
+
+use crate::progress::{Bar, BarBuilder, BarExt};
+
+/// Epoch and step progress bars for training loops, modeled on usage of tqdm in PyTorch training scripts.
+///
+/// Epoch bar is displayed at position 0 and step bar is displayed below it.
+/// Metrics set using [set_metric](Self::set_metric) are displayed as postfix of step bar
+/// and are copied to epoch bar at the end of each epoch.
+///
+/// # Example
+///
+/// ```
+/// use kdam::ml::EpochBar;
+///
+/// let mut pb = EpochBar::new(3, 100);
+///
+/// for _ in 0..3 {
+///     for step in 0..100 {
+///         pb.step();
+///         pb.set_metric("loss", 1.0 / (step + 1) as f64);
+///     }
+///
+///     pb.end_epoch();
+/// }
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug)]
+pub struct EpochBar {
+    epoch_pb: Bar,
+    metrics: Vec<(String, f64)>,
+    step_pb: Bar,
+}
+
+impl EpochBar {
+    /// Create a new instance of [EpochBar](crate::ml::EpochBar) with number of epochs and steps per epoch.
+    pub fn new(epochs: usize, steps: usize) -> Self {
+        Self::from_bars(
+            BarBuilder::default()
+                .total(epochs)
+                .desc("epoch")
+                .unit("epoch")
+                .position(0)
+                .build()
+                .unwrap(),
+            BarBuilder::default()
+                .total(steps)
+                .desc("step")
+                .unit("step")
+                .position(1)
+                .leave(false)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    /// Create a new instance of [EpochBar](crate::ml::EpochBar) from custom epoch and step bars.
+    pub fn from_bars(epoch_pb: Bar, step_pb: Bar) -> Self {
+        Self {
+            epoch_pb,
+            metrics: vec![],
+            step_pb,
+        }
+    }
+
+    /// Returns a mutable reference to epoch bar.
+    pub fn epoch_bar(&mut self) -> &mut Bar {
+        &mut self.epoch_pb
+    }
+
+    /// Returns a mutable reference to step bar.
+    pub fn step_bar(&mut self) -> &mut Bar {
+        &mut self.step_pb
+    }
+
+    /// Returns value of a metric, if it is set.
+    pub fn get_metric(&self, name: &str) -> Option<f64> {
+        self.metrics
+            .iter()
+            .find(|(x, _)| x == name)
+            .map(|(_, x)| *x)
+    }
+
+    /// Set value of a metric (e.g. `loss`), which is displayed in scientific formatting.
+    /// Metrics are displayed in order they are first set.
+    pub fn set_metric<T: Into<String>>(&mut self, name: T, value: f64) {
+        let name = name.into();
+
+        match self.metrics.iter_mut().find(|(x, _)| *x == name) {
+            Some((_, x)) => *x = value,
+            None => self.metrics.push((name, value)),
+        }
+
+        let postfix = self.fmt_metrics();
+        self.step_pb.set_postfix(postfix);
+    }
+
+    /// Advance step bar by one step.
+    /// Returns wheter step bar was rendered or not.
+    pub fn step(&mut self) -> bool {
+        self.step_pb.update(1)
+    }
+
+    /// Advance epoch bar by one epoch, display metrics of epoch with it and reset step bar.
+    pub fn end_epoch(&mut self) {
+        if !self.metrics.is_empty() {
+            let postfix = self.fmt_metrics();
+            self.epoch_pb.set_postfix(postfix);
+        }
+
+        self.epoch_pb.update(1);
+
+        if !self.epoch_pb.completed() {
+            self.step_pb.reset(None);
+        } else {
+            self.step_pb.clear();
+        }
+    }
+
+    /// Returns metrics formatted as `name=value` pairs.
+    fn fmt_metrics(&self) -> String {
+        self.metrics
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, crate::format::format_num(*value)))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,115 @@
+//! Export progress as [OpenTelemetry](https://opentelemetry.io) metrics.
+//!
+//! Services running batch jobs can surface progress in dashboards, not just terminals.
+//! Metrics are recorded at each refresh of progress bar using [MetricsRenderer](crate::otel::MetricsRenderer).
+//!
+//! | Metric          | Instrument | Description                                 |
+//! |-----------------|------------|---------------------------------------------|
+//! | `kdam.items`    | counter    | number of items processed                   |
+//! | `kdam.progress` | gauge      | fraction of progress done, between 0 and 1  |
+//! | `kdam.rate`     | gauge      | progress update rate in items per second    |
+//! | `kdam.eta`      | gauge      | remaining time in seconds                   |
+//!
+//! Every metric has a `desc` attribute which is description of progress bar.
+//!
+//! # Example
+//!
+//! ```
+//! use kdam::{otel::MetricsRenderer, term::AnsiRenderer, tqdm, BarExt};
+//!
+//! let meter = opentelemetry::global::meter("kdam");
+//! let mut pb = tqdm!(
+//!     total = 100,
+//!     desc = "indexing",
+//!     renderer = MetricsRenderer::new(&meter, AnsiRenderer)
+//! );
+//!
+//! for _ in 0..100 {
+//!     pb.update(1);
+//! }
+//!
+//! eprintln!();
+//! ```
+
+use crate::progress::Bar;
+use crate::term::Renderer;
+use opentelemetry::metrics::{Counter, Gauge, Meter};
+use opentelemetry::KeyValue;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Renderer which records progress as OpenTelemetry metrics and then renders it using an inner renderer.
+#[derive(Debug)]
+pub struct MetricsRenderer<R: Renderer> {
+    eta: Gauge<f64>,
+    inner: R,
+    items: Counter<u64>,
+    last_counter: AtomicUsize,
+    progress: Gauge<f64>,
+    rate: Gauge<f64>,
+}
+
+impl<R: Renderer> MetricsRenderer<R> {
+    /// Create a new renderer which creates instruments using `meter` and renders bar using `inner` renderer.
+    pub fn new(meter: &Meter, inner: R) -> Self {
+        Self {
+            eta: meter
+                .f64_gauge("kdam.eta")
+                .with_description("Remaining time of progress")
+                .with_unit("s")
+                .build(),
+            inner,
+            items: meter
+                .u64_counter("kdam.items")
+                .with_description("Number of items processed")
+                .build(),
+            last_counter: AtomicUsize::new(0),
+            progress: meter
+                .f64_gauge("kdam.progress")
+                .with_description("Fraction of progress done")
+                .build(),
+            rate: meter
+                .f64_gauge("kdam.rate")
+                .with_description("Progress update rate")
+                .with_unit("1/s")
+                .build(),
+        }
+    }
+
+    /// Record metrics of bar.
+    fn record(&self, pb: &Bar) {
+        let attributes = [KeyValue::new("desc", pb.get_desc().to_owned())];
+        let counter = pb.get_counter();
+        let last_counter = self.last_counter.swap(counter, Ordering::AcqRel);
+
+        if counter > last_counter {
+            self.items.add((counter - last_counter) as u64, &attributes);
+        }
+
+        if !pb.indefinite() {
+            self.progress.record(pb.percentage(), &attributes);
+        }
+
+        let rate = pb.rate();
+
+        if rate.is_finite() {
+            self.rate.record(rate as f64, &attributes);
+        }
+
+        let eta = pb.remaining_time();
+
+        if !pb.indefinite() && eta.is_finite() {
+            self.eta.record(eta as f64, &attributes);
+        }
+    }
+}
+
+impl<R: Renderer> Renderer for MetricsRenderer<R> {
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()> {
+        self.record(pb);
+        self.inner.render(pb, text)
+    }
+
+    fn clear(&self, pb: &Bar) -> std::io::Result<()> {
+        self.inner.clear(pb)
+    }
+}
@@ -1,7 +1,9 @@
 use crate::format;
-use crate::progress::BarExt;
+use crate::progress::{BarExt, Clock, Latency, Notify, Secondary, SystemClock, Timeline};
 use crate::styles::Animation;
-use crate::term::{Colorizer, Writer};
+use crate::term::{AnsiRenderer, Colorizer, Renderer, Writer};
+use std::borrow::Cow;
+use std::sync::Arc;
 
 #[cfg(feature = "spinner")]
 use crate::styles::Spinner;
@@ -9,6 +11,191 @@ use crate::styles::Spinner;
 #[cfg(feature = "template")]
 use formatx::Template;
 
+/// Policy for displaying progress when counter exceeds total.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, Overflow};
+///
+/// let mut pb = tqdm!(total = 100, overflow = Overflow::Show);
+/// pb.set_counter(105);
+///
+/// assert!(pb.render().contains(" 100+5/100 "));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Counter is displayed as total i.e. `100/100`.
+    Clamp,
+    /// Total is extended to counter i.e. `105/105`.
+    Extend,
+    /// Extra iterations are displayed separately i.e. `100+5/100`.
+    Show,
+}
+
+/// Style of displaying remaining time of [Bar](crate::Bar).
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, Eta};
+///
+/// let mut pb = tqdm!(total = 100, eta = Eta::Finish);
+/// pb.update(10);
+///
+/// assert!(pb.render().contains("<ETA "));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eta {
+    /// Countdown of remaining time i.e. `00:41`.
+    Remaining,
+    /// Projected finish time in local time i.e. `ETA 14:32`.
+    Finish,
+    /// Countdown followed by projected finish time i.e. `00:41 (ETA 14:32)`.
+    Both,
+}
+
+/// Text which keeps its space when terminal is too narrow for whole bar, other text is truncated first.
+/// Progress meter is never shrunk below [min_ncols](crate::BarBuilder::min_ncols) for either of them.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, LayoutPriority};
+///
+/// let mut pb = tqdm!(
+///     total = 100,
+///     desc = "important",
+///     postfix = "loss=0.42",
+///     min_ncols = 10_i16,
+///     layout_priority = LayoutPriority::Desc
+/// );
+///
+/// pb.update(10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutPriority {
+    /// Description is kept, postfix is truncated first.
+    Desc,
+    /// Postfix is kept, description is truncated first.
+    Postfix,
+}
+
+/// Behaviour of [Bar](crate::Bar) once progress is completed.
+///
+/// Summary of [OnComplete::ReplaceWith](crate::OnComplete::ReplaceWith) can contain `{desc}`, `{count}`, `{total}`,
+/// `{unit}`, `{elapsed}` and `{rate}` placeholders, which are replaced by their formatted values.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, OnComplete};
+///
+/// let mut pb = tqdm!(
+///     total = 1204,
+///     unit = " files",
+///     on_complete = OnComplete::ReplaceWith("✓ copied {count}{unit} in {elapsed}".into())
+/// );
+///
+/// pb.update(1204);
+/// assert_eq!(pb.render(), "✓ copied 1204 files in 00:00");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnComplete {
+    /// Completed bar is kept as it is.
+    Keep,
+    /// Completed bar is cleared, unless it is at position 0 (same as `leave = false`).
+    Clear,
+    /// Completed bar is replaced with a summary line.
+    ReplaceWith(Cow<'static, str>),
+}
+
+/// State of work tracked by [Bar](crate::Bar).
+///
+/// Bar starts in [Running](crate::BarState::Running) state. A [Pending](crate::BarState::Pending) bar becomes running
+/// on its first update and a running bar becomes [Done](crate::BarState::Done) once its progress is completed,
+/// other transitions are made using [Bar::set_state](crate::Bar::set_state).
+/// Pending bars are greyed out, paused and failed bars are rendered with an icon before description.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::Colorizer, tqdm, BarExt, BarState};
+///
+/// let mut pb = tqdm!(total = 10, desc = "upload");
+/// pb.set_state(BarState::Pending);
+/// pb.update(5);
+/// assert_eq!(pb.get_state(), BarState::Running);
+///
+/// pb.set_state(BarState::Failed);
+/// assert!(pb.render().trim_ansi().starts_with("✗ upload: "));
+///
+/// pb.set_state(BarState::Running);
+/// pb.update(5);
+/// assert_eq!(pb.get_state(), BarState::Done);
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarState {
+    /// Work is queued and not started yet.
+    Pending,
+    /// Work is in progress.
+    Running,
+    /// Work is paused.
+    Paused,
+    /// Work is completed.
+    Done,
+    /// Work is failed.
+    Failed,
+}
+
+impl BarState {
+    /// Returns icon and its colour, which are displayed before description of bar.
+    fn icon(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Paused => Some(("⏸", "yellow")),
+            Self::Failed => Some(("✗", "red")),
+            _ => None,
+        }
+    }
+}
+
+/// Callback stored by [Bar](crate::Bar), which can capture state (i.e. a channel sender) and is shared with forked bars.
+pub(crate) struct Hook<F: ?Sized>(Arc<F>);
+
+impl<F: ?Sized> Clone for Hook<F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: ?Sized> std::fmt::Debug for Hook<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hook").finish_non_exhaustive()
+    }
+}
+
+type StallHook = Hook<dyn Fn(&Bar) + Send + Sync>;
+type StateChangeHook = Hook<dyn Fn(&Bar, BarState) + Send + Sync>;
+type PreRenderHook = Hook<dyn Fn(&mut Bar) + Send + Sync>;
+type PostRenderHook = Hook<dyn Fn(&Bar, &mut String) + Send + Sync>;
+
+/// Escape sequence which removes progress reported to terminal using `OSC 9;4`.
+const TASKBAR_RESET: &str = "\x1b]9;4;0\x07";
+
+/// Removes progress reported to terminal when bar is dropped, so that stale progress isn't left in taskbar.
+#[derive(Debug, Default)]
+struct TaskbarReset(Option<Writer>);
+
+impl Drop for TaskbarReset {
+    fn drop(&mut self) {
+        if let Some(writer) = &self.0 {
+            let _ = writer.try_print(format_args!("{}", TASKBAR_RESET));
+        }
+    }
+}
+
 /// Core implemention of console progress bar.
 ///
 /// # Example
@@ -30,35 +217,82 @@ use formatx::Template;
 #[derive(Debug)]
 pub struct Bar {
     // CUSTOMIZABLE FIELDS
+    accessible: bool,
     animation: Animation,
+    ascii: bool,
     #[cfg(feature = "template")]
     bar_format: Option<Template>,
+    bounce: bool,
     colour: String,
+    colour_map: Vec<(f32, String)>,
     delay: f32,
-    desc: String,
+    desc: Cow<'static, str>,
+    desc_truncate: format::Truncate,
+    desc_width: Option<usize>,
     disable: bool,
     dynamic_miniters: bool,
     dynamic_ncols: bool,
+    extra_lines: u16,
     force_refresh: bool,
     initial: usize,
     inverse_unit: bool,
-    leave: bool,
+    layout_priority: LayoutPriority,
+    on_complete: OnComplete,
+    max_ncols: Option<i16>,
+    min_ncols: Option<i16>,
     mininterval: f32,
     miniters: usize,
     ncols: i16,
+    notify: Option<Notify>,
+    on_stall: Option<StallHook>,
+    on_state_change: Option<StateChangeHook>,
+    post_render: Option<PostRenderHook>,
+    pre_render: Option<PreRenderHook>,
+    eta: Eta,
+    overflow: Overflow,
     position: u16,
-    postfix: String,
+    postfix: Cow<'static, str>,
+    render_budget: Option<f32>,
+    sizeof_options: format::SizeofOptions,
+    renderer: Arc<dyn Renderer>,
+    clock: Arc<dyn Clock>,
     total: usize,
     #[cfg(feature = "spinner")]
     spinner: Option<Spinner>,
-    unit: String,
+    stall_colour: String,
+    stall_timeout: Option<std::time::Duration>,
+    taskbar_progress: bool,
+    taskbar_reset: TaskbarReset,
+    thousands_separator: Option<String>,
+    unit: Cow<'static, str>,
     unit_divisor: usize,
     unit_scale: bool,
+    wrap_lines: bool,
     writer: Writer,
     // NON CUSTOMIZABLE FIELDS
+    auto_position: Option<crate::thread::lock::PositionGuard>,
     bar_length: i16,
+    columns: Option<u16>,
     counter: usize,
-    timer: std::time::Instant,
+    cumulative: (f32, usize),
+    details: bool,
+    elapsed_offset: std::time::Duration,
+    failed: Option<usize>,
+    frame: String,
+    heartbeat: Option<(f32, f32, usize)>,
+    pub(crate) decimals: Option<usize>,
+    history: Option<Timeline>,
+    last_progress: (usize, f32),
+    marquee_offset: usize,
+    notified: bool,
+    latency: Option<Latency>,
+    render_cost: f32,
+    repeats: usize,
+    secondary: Option<Secondary>,
+    stalled: bool,
+    state: BarState,
+    timed: bool,
+    timer: std::time::Duration,
     pub elapsed_time: f32,
     user_ncols: Option<i16>,
 }
@@ -66,37 +300,85 @@ pub struct Bar {
 impl Default for Bar {
     fn default() -> Self {
         Self {
-            desc: "".to_owned(),
+            accessible: false,
+            ascii: false,
+            desc: Cow::Borrowed(""),
+            desc_truncate: format::Truncate::Right,
+            desc_width: None,
             total: 0,
-            leave: true,
+            on_complete: OnComplete::Keep,
             ncols: 10,
+            min_ncols: None,
+            max_ncols: None,
+            layout_priority: LayoutPriority::Postfix,
+            notify: None,
+            on_stall: None,
+            on_state_change: None,
+            post_render: None,
+            pre_render: None,
+            eta: Eta::Remaining,
+            overflow: Overflow::Extend,
             mininterval: 0.1,
             miniters: 1,
             dynamic_miniters: false,
             disable: false,
-            unit: "it".to_owned(),
+            unit: Cow::Borrowed("it"),
+            sizeof_options: format::SizeofOptions::default(),
             unit_scale: false,
             dynamic_ncols: false,
+            extra_lines: 0,
             initial: 0,
             inverse_unit: false,
             #[cfg(feature = "template")]
             bar_format: None,
+            bounce: false,
             position: 0,
-            postfix: "".to_string(),
+            postfix: Cow::Borrowed(""),
+            render_budget: None,
+            renderer: Arc::new(AnsiRenderer),
+            clock: Arc::new(SystemClock),
             unit_divisor: 1000,
             colour: "default".to_owned(),
+            colour_map: vec![],
             delay: 0.0,
             animation: Animation::Tqdm,
             #[cfg(feature = "spinner")]
             spinner: None,
+            stall_colour: "yellow".to_owned(),
+            stall_timeout: None,
+            taskbar_progress: false,
+            taskbar_reset: TaskbarReset::default(),
+            thousands_separator: None,
+            wrap_lines: false,
             writer: Writer::Stderr,
             force_refresh: false,
+            auto_position: None,
             counter: 0,
-            timer: std::time::Instant::now(),
+            cumulative: (0.0, 0),
+            details: false,
+            elapsed_offset: std::time::Duration::ZERO,
+            failed: None,
+            decimals: None,
+            heartbeat: None,
+            history: None,
+            last_progress: (0, 0.0),
+            notified: false,
+            latency: None,
+            render_cost: 0.0,
+            repeats: 0,
+            secondary: None,
+            marquee_offset: 0,
+            stalled: false,
+            state: BarState::Running,
+            timed: false,
+            timer: SystemClock.now(),
             elapsed_time: 0.0,
             user_ncols: None,
             bar_length: 0,
+            columns: None,
+            frame: String::new(),
         }
+        .with_defaults()
         .init()
     }
 }
@@ -122,6 +404,57 @@ impl Bar {
         .init()
     }
 
+    /// Create a new time driven instance of [Bar](crate::Bar), whose progress advances with elapsed time
+    /// until `duration` is elapsed. Useful for countdowns, timed benchmarks and sleeping with progress.
+    ///
+    /// Counter is set from elapsed time (with a resolution of 0.1 seconds) whenever bar is updated or refreshed,
+    /// so increments passed to update methods are ignored. Bar can be driven using [monitor](crate::monitor) mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{Bar, BarExt, MockClock};
+    /// use std::time::Duration;
+    ///
+    /// let clock = MockClock::default();
+    /// let mut pb = Bar::for_duration(Duration::from_secs(3));
+    /// pb.set_clock(clock.clone());
+    ///
+    /// clock.advance(Duration::from_millis(1200));
+    /// pb.refresh();
+    /// assert!(pb.render().contains("1.2/3.0 [00:01<00:01, 1.00s/s]"));
+    ///
+    /// clock.advance(Duration::from_secs(5));
+    /// pb.refresh();
+    /// assert!(pb.completed());
+    ///
+    /// eprint!("\n");
+    /// ```
+    ///
+    /// Sleep with progress using monitor mode.
+    ///
+    /// ```
+    /// use kdam::Bar;
+    /// use std::time::Duration;
+    ///
+    /// let pb = Bar::for_duration(Duration::from_millis(300));
+    /// let (pb, monitor) = kdam::monitor::bar(pb, 0.05);
+    /// assert!(monitor.join(Duration::from_secs(5)));
+    /// assert!(pb.lock().unwrap().completed());
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn for_duration(duration: std::time::Duration) -> Self {
+        Self {
+            total: ((duration.as_secs_f32() * 10.0).round() as usize).max(1),
+            unit: Cow::Borrowed("s"),
+            decimals: Some(1),
+            timed: true,
+            ..Default::default()
+        }
+        .init()
+    }
+
     /// Create a instance of [BarBuilder](crate::BarBuilder).
     ///
     /// # Example
@@ -133,6 +466,368 @@ impl Bar {
         BarBuilder::default()
     }
 
+    /// Create a new bar which shares styling (description, postfix, unit, animation, colour, renderer, clock etc.) of this bar,
+    /// but tracks its own counter and timer. Recorded history and latency are not copied.
+    ///
+    /// Forked bar is placed at next free row below this bar from a global registry of positions,
+    /// like [with_position_auto](Self::with_position_auto), so that forks don't overwrite each other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let pb = tqdm!(total = 100, desc = "pipeline", postfix = "stage=1");
+    /// let mut child1 = pb.fork();
+    /// let child2 = pb.fork();
+    ///
+    /// assert!(child1.render().ends_with(", stage=1]"));
+    /// assert!(child1.get_position() > pb.get_position());
+    /// assert_ne!(child1.get_position(), child2.get_position());
+    /// ```
+    pub fn fork(&self) -> Self {
+        let mut child = self.fork_styles();
+        let guard =
+            crate::thread::lock::PositionGuard::acquire_from(self.position + 1 + self.extra_lines);
+        child.position = guard.position();
+        child.auto_position = Some(guard);
+        child
+    }
+
+    /// Create a new bar with styling of this bar at same position, see [fork](Self::fork).
+    fn fork_styles(&self) -> Self {
+        Self {
+            accessible: self.accessible,
+            animation: self.animation.clone(),
+            ascii: self.ascii,
+            #[cfg(feature = "template")]
+            bar_format: self.bar_format.clone(),
+            bounce: self.bounce,
+            colour: self.colour.clone(),
+            colour_map: self.colour_map.clone(),
+            delay: self.delay,
+            desc: self.desc.clone(),
+            desc_truncate: self.desc_truncate,
+            desc_width: self.desc_width,
+            disable: self.disable,
+            dynamic_miniters: self.dynamic_miniters,
+            dynamic_ncols: self.dynamic_ncols,
+            extra_lines: self.extra_lines,
+            force_refresh: self.force_refresh,
+            initial: 0,
+            inverse_unit: self.inverse_unit,
+            layout_priority: self.layout_priority,
+            on_complete: self.on_complete.clone(),
+            max_ncols: self.max_ncols,
+            min_ncols: self.min_ncols,
+            mininterval: self.mininterval,
+            miniters: self.miniters,
+            ncols: self.ncols,
+            notify: self.notify.clone(),
+            on_stall: self.on_stall.clone(),
+            on_state_change: self.on_state_change.clone(),
+            post_render: self.post_render.clone(),
+            pre_render: self.pre_render.clone(),
+            eta: self.eta,
+            overflow: self.overflow,
+            position: self.position,
+            postfix: self.postfix.clone(),
+            render_budget: self.render_budget,
+            sizeof_options: self.sizeof_options,
+            renderer: self.renderer.clone(),
+            clock: self.clock.clone(),
+            total: self.total,
+            #[cfg(feature = "spinner")]
+            spinner: self.spinner.clone(),
+            stall_colour: self.stall_colour.clone(),
+            stall_timeout: self.stall_timeout,
+            taskbar_progress: self.taskbar_progress,
+            thousands_separator: self.thousands_separator.clone(),
+            unit: self.unit.clone(),
+            unit_divisor: self.unit_divisor,
+            unit_scale: self.unit_scale,
+            wrap_lines: self.wrap_lines,
+            writer: self.writer.clone(),
+            decimals: self.decimals,
+            timed: self.timed,
+            heartbeat: self.heartbeat.map(|(interval, _, _)| (interval, 0.0, 0)),
+            history: self.history.as_ref().map(|x| Timeline::new(x.capacity())),
+            latency: self.latency.as_ref().map(|x| Latency::new(x.capacity())),
+            secondary: self.secondary.as_ref().map(|x| x.fork()),
+            user_ncols: self.user_ncols,
+            details: self.details,
+            ..Default::default()
+        }
+        .init()
+    }
+
+    /// Split bar into forked child bars for parallel phases, see [fork](Self::fork).
+    /// Total of bar is divided between children in proportion of `weights`,
+    /// and children are placed at next free rows below this bar from a global registry of positions, like forks.
+    /// Progress of children can be added back to this bar using [merge](Self::merge).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100, desc = "pipeline");
+    /// let mut phases = pb.split(&[3, 1]);
+    /// assert_eq!(phases[0].get_total(), 75);
+    /// assert_eq!(phases[1].get_total(), 25);
+    ///
+    /// phases[0].update(75);
+    /// phases[1].update(20);
+    ///
+    /// assert!(phases[0].get_position() > pb.get_position());
+    /// assert!(phases[1].get_position() > phases[0].get_position());
+    ///
+    /// pb.merge(&phases);
+    /// assert_eq!(pb.get_counter(), 95);
+    /// eprint!("\n\n\n");
+    /// ```
+    pub fn split(&self, weights: &[usize]) -> Vec<Self> {
+        let sum = weights.iter().sum::<usize>().max(1);
+        let mut remaining = self.total;
+        let mut min_position = self.position + 1 + self.extra_lines;
+
+        weights
+            .iter()
+            .enumerate()
+            .map(|(i, weight)| {
+                let mut child = self.fork_styles();
+                child.total = if i + 1 == weights.len() {
+                    remaining
+                } else {
+                    (self.total as u128 * *weight as u128 / sum as u128) as usize
+                };
+                remaining = remaining.saturating_sub(child.total);
+                let guard = crate::thread::lock::PositionGuard::acquire_from(min_position);
+                child.position = guard.position();
+                child.auto_position = Some(guard);
+                min_position = child.position + 1 + self.extra_lines;
+                child
+            })
+            .collect()
+    }
+
+    /// Add progress made by child bars (created using [split](Self::split) or [fork](Self::fork)) to this bar.
+    pub fn merge(&mut self, children: &[Self]) {
+        self.counter += children
+            .iter()
+            .map(|x| x.counter.saturating_sub(x.initial))
+            .sum::<usize>();
+
+        if let Some(secondary) = &mut self.secondary {
+            secondary.add(
+                children
+                    .iter()
+                    .filter_map(|x| x.secondary.as_ref())
+                    .map(|x| x.get_counter())
+                    .sum(),
+            );
+        }
+    }
+
+    /// Enable recording of progress history, which is used by [finish_report](Self::finish_report), detailed stats,
+    /// sparklines and [export](crate::export) functions. Last 1000 samples are kept, see [Timeline](crate::Timeline)
+    /// and [with_history_capacity](Self::with_history_capacity).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100).with_history();
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    /// }
+    ///
+    /// pb.finish_report();
+    /// ```
+    pub fn with_history(mut self) -> Self {
+        self.history = Some(Timeline::default());
+        self
+    }
+
+    /// Print a single status line every `interval` instead of redrawing bar, when writer is not a terminal.
+    /// CI logs show liveness this way without thousands of redraw lines, i.e. `[02:13] 45% 4500/10000 12.00it/s`.
+    /// This has no effect if writer is a terminal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    /// use std::time::Duration;
+    ///
+    /// let mut pb = tqdm!(total = 100).ci_heartbeat(Duration::from_secs(30));
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    /// }
+    /// ```
+    pub fn ci_heartbeat(mut self, interval: std::time::Duration) -> Self {
+        self.heartbeat = if self.writer.is_terminal() {
+            None
+        } else {
+            Some((interval.as_secs_f32(), 0.0, self.counter))
+        };
+        self
+    }
+
+    /// Enable recording of time between updates, which is divided equally between updated iterations.
+    /// Latency percentiles are available using [get_latency](Self::get_latency) and `p50`, `p95`, `p99` template placeholders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100).with_latency();
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    ///     let postfix = pb.get_latency().unwrap().postfix();
+    ///     pb.set_postfix(postfix);
+    /// }
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn with_latency(mut self) -> Self {
+        self.latency = Some(Latency::default());
+        self
+    }
+
+    /// Enable recording of progress history like [with_history](Self::with_history), keeping last `capacity` samples.
+    /// Samples are available using [get_history](Self::get_history) and can be exported to CSV or JSON
+    /// using [export::timeline_csv](crate::export::timeline_csv) and [export::timeline_json](crate::export::timeline_json).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100).with_history_capacity(100);
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    /// }
+    ///
+    /// let csv = kdam::export::timeline_csv(&pb).unwrap();
+    /// assert!(csv.starts_with("elapsed,n,rate,eta\n"));
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn with_history_capacity(mut self, capacity: usize) -> Self {
+        self.history = Some(Timeline::new(capacity));
+        self
+    }
+
+    /// Track a secondary counter alongside counter of bar, i.e. bytes along with records.
+    /// Secondary counter is updated using [update_secondary](Self::update_secondary), see [Secondary](crate::Secondary).
+    pub fn with_secondary(mut self, secondary: Secondary) -> Self {
+        self.secondary = Some(secondary);
+        self
+    }
+
+    /// Change bar colour as progress crosses thresholds.
+    /// Each entry is a fraction of progress (between 0 and 1) and colour used from that fraction onwards.
+    /// Progress below first threshold is displayed using `colour` option.
+    /// Returns an [InvalidColour](crate::Error::InvalidColour) error if any colour is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100)
+    ///     .colour_map(&[(0.0, "red"), (0.5, "yellow"), (0.9, "green")])
+    ///     .unwrap();
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    /// }
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn colour_map(mut self, colour_map: &[(f32, &str)]) -> Result<Self, crate::Error> {
+        for (_, colour) in colour_map {
+            crate::term::Colour::parse(colour)?;
+        }
+
+        self.colour_map = colour_map
+            .iter()
+            .map(|(threshold, colour)| (*threshold, colour.to_string()))
+            .collect();
+        self.colour_map.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(self)
+    }
+
+    /// Send a notification once, when progress is completed.
+    /// Long running jobs can ping their owners this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, Notify};
+    ///
+    /// let mut pb = tqdm!(total = 100).notify_on_complete(Notify::TerminalBell);
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    /// }
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn notify_on_complete(mut self, notify: Notify) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Set position of bar to next free row from a global registry of positions.
+    /// Row is freed when bar is dropped, so independent components drawing bars don't collide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// let pb1 = tqdm!(total = 100).with_position_auto();
+    /// let pb2 = tqdm!(total = 100).with_position_auto();
+    /// assert_ne!(pb1.get_position(), pb2.get_position());
+    /// ```
+    pub fn with_position_auto(mut self) -> Self {
+        self.auto_position = None;
+        let guard = crate::thread::lock::PositionGuard::acquire();
+        self.position = guard.position();
+        self.auto_position = Some(guard);
+        self
+    }
+
+    /// Apply process wide default options, see [set_defaults](crate::set_defaults).
+    fn with_defaults(mut self) -> Self {
+        let defaults = crate::progress::get_defaults();
+
+        if let Some(animation) = defaults.animation {
+            self.animation = animation;
+        }
+
+        if let Some(colour) = defaults.colour {
+            self.colour = colour;
+        }
+
+        if let Some(mininterval) = defaults.mininterval {
+            self.mininterval = mininterval;
+        }
+
+        if let Some(writer) = defaults.writer {
+            self.writer = writer;
+        }
+
+        self
+    }
+
     fn init(mut self) -> Self {
         if self.user_ncols.is_none() {
             if let Ok(ncols) = std::env::var("KDAM_NCOLS") {
@@ -144,7 +839,14 @@ impl Bar {
         }
 
         self.counter = self.initial;
-        self.timer = std::time::Instant::now();
+        self.timer = self.clock.now();
+        self.last_progress = (self.counter, 0.0);
+        self.stalled = false;
+
+        if self.taskbar_progress && !self.writer.is_null() {
+            self.taskbar_reset = TaskbarReset(Some(self.writer.clone()));
+        }
+
         self
     }
 
@@ -152,6 +854,11 @@ impl Bar {
     // GETTERS
     // -----------------------------------------------------------------------------------------
 
+    /// Get accessible value.
+    pub(crate) fn get_accessible(&self) -> bool {
+        self.accessible
+    }
+
     /// Get bar length value.
     pub(crate) fn get_bar_length(&self) -> i16 {
         self.bar_length
@@ -162,19 +869,55 @@ impl Bar {
         self.counter
     }
 
+    /// Get description value.
+    pub fn get_desc(&self) -> &str {
+        &self.desc
+    }
+
+    /// Returns wheter detailed stats are displayed in postfix or not, see [set_details](Self::set_details).
+    pub fn get_details(&self) -> bool {
+        self.details
+    }
+
     /// Get disable value.
     pub fn get_disable(&self) -> bool {
         self.disable
     }
 
+    /// Get number of failed items, see [update_with_status](Self::update_with_status).
+    pub fn get_failed(&self) -> usize {
+        self.failed.unwrap_or(0)
+    }
+
+    /// Get state of work tracked by bar, see [BarState](crate::BarState).
+    pub fn get_state(&self) -> BarState {
+        self.state
+    }
+
     /// Get force refresh value.
     pub(crate) fn get_force_refresh(&self) -> bool {
         self.force_refresh
     }
 
-    /// Get leave value.
+    /// Get recorded history samples of `(elapsed time, counter, rate)`, if history is enabled.
+    /// See [with_history](Self::with_history).
+    pub fn get_history(&self) -> Option<&Timeline> {
+        self.history.as_ref()
+    }
+
+    /// Get recorded latency samples, if latency recording is enabled.
+    pub fn get_latency(&self) -> Option<&Latency> {
+        self.latency.as_ref()
+    }
+
+    /// Get leave value, which is `false` if completed bar is cleared.
     pub fn get_leave(&self) -> bool {
-        self.leave
+        self.on_complete != OnComplete::Clear
+    }
+
+    /// Get mininterval value.
+    pub(crate) fn get_mininterval(&self) -> f32 {
+        self.mininterval
     }
 
     /// Get ncols value.
@@ -182,16 +925,36 @@ impl Bar {
         self.ncols
     }
 
+    /// Get secondary counter, if it is attached using [with_secondary](Self::with_secondary).
+    pub fn get_secondary(&self) -> Option<&Secondary> {
+        self.secondary.as_ref()
+    }
+
+    /// Get number of times progress bar is reset using [reset_keep_elapsed](Self::reset_keep_elapsed).
+    pub fn get_repeats(&self) -> usize {
+        self.repeats
+    }
+
     /// Get position value.
     pub fn get_position(&self) -> u16 {
         self.position
     }
 
+    /// Get number of rows reserved below bar, see [BarBuilder::extra_lines](crate::BarBuilder::extra_lines).
+    pub fn get_extra_lines(&self) -> u16 {
+        self.extra_lines
+    }
+
     /// Get total value.
     pub fn get_total(&self) -> usize {
         self.total
     }
 
+    /// Get unit value.
+    pub(crate) fn get_unit(&self) -> &str {
+        &self.unit
+    }
+
     /// Get writer cloned value.
     pub(crate) fn get_writer(&self) -> Writer {
         self.writer.clone()
@@ -209,7 +972,7 @@ impl Bar {
     /// Set/Modify bar_format property.
     #[cfg(feature = "template")]
     #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
-    pub fn set_bar_format<T: Into<String>>(&mut self, bar_format: T) -> Result<(), formatx::Error> {
+    pub fn set_bar_format<T: Into<String>>(&mut self, bar_format: T) -> Result<(), crate::Error> {
         let bar_format = bar_format.into().parse::<Template>()?;
         let mut bar_format_check = bar_format.clone();
         bar_format_check.replace("desc", "");
@@ -217,8 +980,20 @@ impl Bar {
         bar_format_check.replace("count", 0);
         bar_format_check.replace("total", 0);
         bar_format_check.replace("elapsed", 0);
+        bar_format_check.replace("total_elapsed", 0);
+        bar_format_check.replace("repeats", 0);
         bar_format_check.replace("remaining", 0);
         bar_format_check.replace("rate", 0.0);
+        bar_format_check.replace("sparkline", "");
+        bar_format_check.replace("secondary", "");
+        bar_format_check.replace("secondary_rate", "");
+        bar_format_check.replace("p50", "");
+        bar_format_check.replace("p95", "");
+        bar_format_check.replace("p99", "");
+        #[cfg(feature = "sysinfo")]
+        bar_format_check.replace("cpu", 0.0);
+        #[cfg(feature = "sysinfo")]
+        bar_format_check.replace("memory", "");
         bar_format_check.replace("unit", "");
         bar_format_check.replace("postfix", "");
         #[cfg(feature = "spinner")]
@@ -230,8 +1005,12 @@ impl Bar {
     }
 
     /// Set/Modify colour property.
-    pub fn set_colour<T: Into<String>>(&mut self, colour: T) {
-        self.colour = colour.into();
+    /// Returns an error if colour is invalid, in that case colour is not modified.
+    pub fn set_colour<T: Into<String>>(&mut self, colour: T) -> Result<(), crate::Error> {
+        let colour = colour.into();
+        crate::term::Colour::parse(&colour)?;
+        self.colour = colour;
+        Ok(())
     }
 
     /// Set/Modify counter property.
@@ -241,7 +1020,26 @@ impl Bar {
 
     /// Set/Modify description property.
     pub fn set_description<T: Into<String>>(&mut self, desc: T) {
-        self.desc = desc.into();
+        self.desc = Cow::Owned(desc.into());
+    }
+
+    /// Display detailed stats after postfix, which includes minimum and maximum rate along with
+    /// a sparkline of throughput if history is enabled (see [with_history](Self::with_history))
+    /// and latency percentiles if latency is enabled (see [with_latency](Self::with_latency)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100).with_latency();
+    /// pb.set_details(true);
+    /// pb.update(1);
+    ///
+    /// assert!(pb.render().contains("p50="));
+    /// ```
+    pub fn set_details(&mut self, details: bool) {
+        self.details = details;
     }
 
     /// Set/Modify disable property.
@@ -254,19 +1052,57 @@ impl Bar {
         self.force_refresh = force_refresh;
     }
 
-    /// Set/Modify leave property.
+    /// Set/Modify leave property, see [set_on_complete](Self::set_on_complete).
     pub fn set_leave(&mut self, leave: bool) {
-        self.leave = leave;
+        self.on_complete = if leave {
+            OnComplete::Keep
+        } else {
+            OnComplete::Clear
+        };
     }
 
-    /// Set/Modify position property.
-    pub fn set_position(&mut self, position: u16) {
+    /// Set/Modify behaviour of bar once progress is completed, see [OnComplete](crate::OnComplete).
+    pub fn set_on_complete(&mut self, on_complete: OnComplete) {
+        self.on_complete = on_complete;
+    }
+
+    /// Set/Modify state of work tracked by bar, `on_state_change` hook is called if state is changed.
+    pub fn set_state(&mut self, state: BarState) {
+        if self.state != state {
+            let previous = std::mem::replace(&mut self.state, state);
+
+            if let Some(on_state_change) = &self.on_state_change {
+                (on_state_change.0)(self, previous);
+            }
+        }
+    }
+
+    /// Set/Modify position property.
+    pub fn set_position(&mut self, position: u16) {
         self.position = position;
     }
 
+    /// Set/Modify number of rows reserved below bar, see [BarBuilder::extra_lines](crate::BarBuilder::extra_lines).
+    pub fn set_extra_lines(&mut self, extra_lines: u16) {
+        self.extra_lines = extra_lines;
+    }
+
     /// Set/Modify postfix property.
     pub fn set_postfix<T: Into<String>>(&mut self, postfix: T) {
-        self.postfix = ", ".to_owned() + &postfix.into();
+        let mut postfix = postfix.into();
+        postfix.insert_str(0, ", ");
+        self.postfix = Cow::Owned(postfix);
+    }
+
+    /// Set/Modify clock property, timer of bar is restarted using new clock.
+    pub fn set_clock<T: Clock + 'static>(&mut self, clock: T) {
+        self.clock = Arc::new(clock);
+        self.timer = self.clock.now();
+    }
+
+    /// Set/Modify renderer property.
+    pub fn set_renderer<T: Renderer + 'static>(&mut self, renderer: T) {
+        self.renderer = Arc::new(renderer);
     }
 
     /// Set/Modify total property.
@@ -288,18 +1124,47 @@ impl Bar {
         }
     }
 
+    /// Returns progress percentage clamped to 1.0, used for displaying progress when counter exceeds total.
+    pub(crate) fn clamped_percentage(&self) -> f64 {
+        self.percentage().min(1.0)
+    }
+
     /// Set/Returns progress elapsed time.
     pub fn elapsed_time(&mut self) -> f32 {
-        self.elapsed_time = self.timer.elapsed().as_secs_f32();
+        self.elapsed_time = self.clock_elapsed().as_secs_f32();
         self.elapsed_time
     }
 
+    /// Returns time elapsed since start of bar, measured using its clock.
+    /// Time elapsed in a previous process is included for bars resumed using [handoff](crate::handoff).
+    pub(crate) fn clock_elapsed(&self) -> std::time::Duration {
+        self.clock.now().saturating_sub(self.timer) + self.elapsed_offset
+    }
+
+    /// Restart timer of bar, so that `elapsed` time is already elapsed.
+    pub(crate) fn resume_elapsed(&mut self, elapsed: std::time::Duration) {
+        self.timer = self.clock.now();
+        self.elapsed_offset = elapsed;
+        self.elapsed_time = elapsed.as_secs_f32();
+        self.last_progress = (self.counter, self.elapsed_time);
+    }
+
+    /// Returns elapsed time across all repeats, see [reset_keep_elapsed](Self::reset_keep_elapsed).
+    pub fn total_elapsed_time(&self) -> f32 {
+        self.cumulative.0 + self.clock_elapsed().as_secs_f32()
+    }
+
+    /// Returns iterations across all repeats, see [reset_keep_elapsed](Self::reset_keep_elapsed).
+    pub fn total_iterations(&self) -> usize {
+        self.cumulative.1 + self.counter
+    }
+
     /// Returns remaining time (ETA) for progress completion.
     pub fn remaining_time(&self) -> f32 {
         if self.indefinite() {
             f32::INFINITY
         } else {
-            (self.total - self.counter) as f32 / self.rate()
+            self.total.saturating_sub(self.counter) as f32 / self.rate()
         }
     }
 
@@ -332,6 +1197,196 @@ impl Bar {
         self.counter != 0
     }
 
+    /// Returns wheter progress is stalled or not i.e. no progress is made within `stall_timeout`.
+    /// Stall state is checked each time progress bar is rendered.
+    pub fn is_stalled(&self) -> bool {
+        self.stalled
+    }
+
+    /// Resets to initial iterations for repeated use (e.g. epochs), keeping track of
+    /// cumulative elapsed time and iterations across repeats.
+    /// Number of repeats can be obtained using [get_repeats](Self::get_repeats).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 10);
+    ///
+    /// for epoch in 0..3 {
+    ///     pb.set_description(format!("epoch {}/3", epoch + 1));
+    ///
+    ///     for _ in 0..10 {
+    ///         pb.update(1);
+    ///     }
+    ///
+    ///     if epoch != 2 {
+    ///         pb.reset_keep_elapsed(None);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(pb.get_repeats(), 2);
+    /// assert_eq!(pb.total_iterations(), 30);
+    /// ```
+    pub fn reset_keep_elapsed(&mut self, total: Option<usize>) {
+        let cumulative = (self.total_elapsed_time(), self.total_iterations());
+        let repeats = self.repeats + 1;
+        BarExt::reset(self, total);
+        self.cumulative = cumulative;
+        self.repeats = repeats;
+    }
+
+    /// Reset bar for a new run like [reset](crate::BarExt::reset), but replace its style and options
+    /// with a bar built using `builder`. Position of bar is kept, so new run is displayed in same row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Bar, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 10, desc = "download", colour = "blue");
+    /// pb.update(10);
+    ///
+    /// pb.reset_with(Bar::builder().total(20).desc("verify").colour("green"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(pb.get_counter(), 0);
+    /// assert_eq!(pb.get_total(), 20);
+    /// assert_eq!(pb.get_desc(), "verify");
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn reset_with(&mut self, builder: BarBuilder) -> Result<(), crate::Error> {
+        let mut pb = builder.build()?;
+        pb.position = self.position;
+        pb.auto_position = self.auto_position.take();
+        pb.bar_length = self.bar_length;
+        *self = pb;
+        Ok(())
+    }
+
+    /// Update progress from a fraction of completion between `0.0` and `1.0`.
+    /// Counter is set to `fraction * total`, so resolution of progress is limited by `total`.
+    /// If `total` is `0`, then it is set to `100` before updating.
+    /// Returns whether progress bar was rendered or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// let mut pb = tqdm!(total = 1000);
+    /// pb.update_to_percent(0.25);
+    /// assert_eq!(pb.get_counter(), 250);
+    ///
+    /// let mut pb = tqdm!();
+    /// pb.update_to_percent(0.5);
+    /// assert_eq!(pb.get_total(), 100);
+    /// assert_eq!(pb.get_counter(), 50);
+    /// ```
+    pub fn update_to_percent(&mut self, fraction: f64) -> bool {
+        if self.indefinite() {
+            self.total = 100;
+        }
+
+        let counter = (fraction.clamp(0.0, 1.0) * self.total as f64).round() as usize;
+        crate::BarExt::update_to(self, counter)
+    }
+
+    /// Update progress with `n_ok` succeeded and `n_err` failed items.
+    /// Once this method is used, bar is rendered as two coloured segments (green for succeeded and red for failed items)
+    /// and counts are displayed in postfix.
+    /// Returns whether progress bar was rendered or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// let mut pb = tqdm!(total = 100);
+    ///
+    /// for i in 0..100 {
+    ///     if i % 10 == 0 {
+    ///         pb.update_with_status(0, 1);
+    ///     } else {
+    ///         pb.update_with_status(1, 0);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(pb.get_counter(), 100);
+    /// assert_eq!(pb.get_failed(), 10);
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn update_with_status(&mut self, n_ok: usize, n_err: usize) -> bool {
+        self.failed = Some(self.get_failed() + n_err);
+        crate::BarExt::update(self, n_ok + n_err)
+    }
+
+    /// Update progress by `n` and secondary counter by `n_secondary`.
+    /// Secondary counter is ignored if it is not attached using [with_secondary](Self::with_secondary).
+    /// Returns whether progress bar was rendered or not.
+    pub fn update_secondary(&mut self, n: usize, n_secondary: usize) -> bool {
+        if let Some(secondary) = &mut self.secondary {
+            secondary.add(n_secondary);
+        }
+
+        crate::BarExt::update(self, n)
+    }
+
+    /// Decorate `iterable` with this bar, limiting iteration rate to at most `rate` items per second.
+    /// See [BarIterator::throttle](crate::BarIterator::throttle).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    ///
+    /// for _ in tqdm!(total = 10).throttle_iter(0..10, 100.0) {
+    ///     // call rate limited api
+    /// }
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn throttle_iter<I: IntoIterator>(
+        self,
+        iterable: I,
+        rate: f32,
+    ) -> crate::BarIterator<I::IntoIter> {
+        crate::BarIterator::new_with_bar(iterable.into_iter(), self).throttle(rate)
+    }
+
+    /// Update progress by `n` after calling `f`, which can modify description, postfix etc. of bar.
+    /// Bar is rendered only once after both changes are applied,
+    /// so a rendered frame never mixes an old description with a new count (e.g. when bar is shared using a `Mutex`).
+    /// Closure receives counter value after update.
+    /// Returns whether progress bar was rendered or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::tqdm;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let pb = Arc::new(Mutex::new(tqdm!(total = 100)));
+    ///
+    /// for file in ["a.txt", "b.txt"] {
+    ///     pb.lock().unwrap().update_with(50, |pb, counter| {
+    ///         pb.set_description(format!("uploaded {}", file));
+    ///         pb.set_postfix(format!("files={}", counter / 50));
+    ///     });
+    /// }
+    ///
+    /// assert_eq!(pb.lock().unwrap().get_desc(), "uploaded b.txt");
+    /// eprint!("\n");
+    /// ```
+    pub fn update_with<F: FnOnce(&mut Self, usize)>(&mut self, n: usize, f: F) -> bool {
+        let counter = self.counter + n;
+        f(self, counter);
+        crate::BarExt::update(self, n)
+    }
+
     // -----------------------------------------------------------------------------------------
     // UPDATE AND PRINTING LOGIC (FOR INTERNAL USE ONLY)
     // -----------------------------------------------------------------------------------------
@@ -339,18 +1394,52 @@ impl Bar {
     /// Checks wheter to trigger a display update or not.
     /// This method will increment internal counter.
     pub(crate) fn trigger(&mut self, n: usize) -> bool {
-        self.counter += n;
+        let previous = self.counter;
+
+        if self.timed {
+            self.counter =
+                ((self.clock_elapsed().as_secs_f32() * self.scale()) as usize).min(self.total);
+        } else {
+            self.counter += n;
+        }
+
+        if self.state == BarState::Pending && self.counter > previous {
+            self.set_state(BarState::Running);
+        }
+
+        if self.state == BarState::Running && self.completed() {
+            self.set_state(BarState::Done);
+        }
+
+        if self.history.is_some() {
+            self.record_history();
+        }
+
+        if let Some(latency) = &mut self.latency {
+            latency.tick_at(n, self.clock.now());
+        }
+
+        if self.stall_timeout.is_some() && self.counter != self.last_progress.0 {
+            self.last_progress = (self.counter, self.clock_elapsed().as_secs_f32());
+            self.stalled = false;
+        }
+
+        if !self.disable && !self.writer.is_null() && crate::progress::is_enabled() {
+            if self.heartbeat.is_some() {
+                self.check_heartbeat();
+                return false;
+            }
 
-        if !self.disable {
             if self.force_refresh {
                 return true;
             }
 
             let completion_constraint = self.counter == self.total;
 
-            let elapsed_time_now = self.timer.elapsed().as_secs_f32();
+            let elapsed_time_now = self.clock_elapsed().as_secs_f32();
             let delay_constraint = self.delay <= elapsed_time_now;
-            let mininterval_constraint = self.mininterval <= (elapsed_time_now - self.elapsed_time);
+            let mininterval_constraint =
+                self.effective_mininterval() <= (elapsed_time_now - self.elapsed_time);
 
             if self.dynamic_miniters && !mininterval_constraint {
                 self.miniters += self.counter;
@@ -359,7 +1448,7 @@ impl Bar {
             let miniters_constraint = if self.miniters <= 1 {
                 true
             } else {
-                self.counter % self.miniters == 0
+                self.counter.is_multiple_of(self.miniters)
             };
 
             if (mininterval_constraint && miniters_constraint && delay_constraint)
@@ -376,6 +1465,187 @@ impl Bar {
         false
     }
 
+    /// Print a heartbeat status line if heartbeat interval has passed,
+    /// or progress is completed or refreshed since last heartbeat.
+    fn check_heartbeat(&mut self) {
+        if let Some((interval, last_time, last_counter)) = self.heartbeat {
+            let elapsed_time_now = self.clock_elapsed().as_secs_f32();
+            let changed = self.counter != last_counter;
+
+            if interval <= elapsed_time_now - last_time
+                || (changed && (self.force_refresh || self.completed()))
+            {
+                self.elapsed_time = elapsed_time_now;
+                self.heartbeat = Some((interval, elapsed_time_now, self.counter));
+                let line = self.fmt_heartbeat();
+                let _ = self.writer.try_print(format_args!("{}\n", line));
+            }
+        }
+    }
+
+    /// Returns `mininterval`, increased so that rendering cost stays within `render_budget` of loop time.
+    pub(crate) fn effective_mininterval(&self) -> f32 {
+        match self.render_budget {
+            Some(render_budget) if render_budget > 0.0 => self
+                .mininterval
+                .max(self.render_cost * (1.0 - render_budget) / render_budget),
+            _ => self.mininterval,
+        }
+    }
+
+    /// Record time taken by rendering and printing of progress bar, started at `started`.
+    /// Rendering cost is smoothed using exponential moving average.
+    pub(crate) fn record_render_cost(&mut self, started: crate::Instant) {
+        if self.render_budget.is_some() {
+            let cost = started.elapsed().as_secs_f32();
+
+            self.render_cost = if self.render_cost == 0.0 {
+                cost
+            } else {
+                0.8 * self.render_cost + 0.2 * cost
+            };
+        }
+    }
+
+    /// Returns text of bar rendered with icon and colour of its state, see [BarState](crate::BarState).
+    fn render_state(&mut self) -> String {
+        if let Some((icon, colour)) = self.state.icon() {
+            let desc = self.desc.clone();
+            self.desc = if desc.is_empty() {
+                Cow::Owned(icon.colorize(colour))
+            } else {
+                Cow::Owned(format!("{} {}", icon.colorize(colour), desc))
+            };
+            let text = self.render_rows();
+            self.desc = desc;
+            text
+        } else if self.state == BarState::Pending {
+            self.render_rows()
+                .trim_ansi()
+                .split('\n')
+                .map(|x| x.colorize("dim"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            self.render_rows()
+        }
+    }
+
+    /// Update stall state and call `on_stall` callback once, when progress becomes stalled.
+    pub(crate) fn check_stall(&mut self) {
+        if let Some(stall_timeout) = self.stall_timeout {
+            let stalled = !self.completed()
+                && stall_timeout.as_secs_f32() <= self.elapsed_time - self.last_progress.1;
+
+            if stalled && !self.stalled {
+                self.stalled = true;
+
+                if let Some(on_stall) = &self.on_stall {
+                    (on_stall.0)(self);
+                }
+            } else if !stalled {
+                self.stalled = false;
+            }
+        }
+    }
+
+    /// Send notification once, when progress becomes completed.
+    pub(crate) fn check_notify(&mut self) {
+        if let Some(notify) = &self.notify {
+            if !self.notified && self.completed() {
+                self.notified = true;
+                notify.send(&self.writer, &self.desc);
+            }
+        }
+    }
+
+    /// Record history sample if `mininterval` has passed since last sample or progress is completed.
+    fn record_history(&mut self) {
+        let elapsed_time_now = self.clock_elapsed().as_secs_f32();
+        let completed = self.completed();
+        let (counter, mininterval) = (self.counter, self.mininterval.max(0.01));
+
+        if let Some(history) = &mut self.history {
+            let record = match history.last() {
+                Some(&(time, last_counter, _)) => {
+                    last_counter != counter && (mininterval <= elapsed_time_now - time || completed)
+                }
+                None => true,
+            };
+
+            if record {
+                let rate = if elapsed_time_now > 0.0 {
+                    counter as f32 / elapsed_time_now
+                } else {
+                    0.0
+                };
+                history.push(elapsed_time_now, counter, rate);
+            }
+        }
+    }
+
+    /// Returns rates between consecutive history samples, decrease in counter (i.e. reset) is treated as zero rate.
+    pub(crate) fn history_rates(&self) -> Vec<f64> {
+        let Some(history) = &self.history else {
+            return vec![];
+        };
+
+        history
+            .iter()
+            .zip(history.iter().skip(1))
+            .filter(|(x, y)| y.0 > x.0)
+            .map(|(x, y)| y.1.saturating_sub(x.1) as f64 / (y.0 - x.0) as f64)
+            .collect()
+    }
+
+    /// Returns sparkline of recent rate history, padded to fixed `width`.
+    pub(crate) fn fmt_sparkline(&self, width: usize) -> String {
+        let rates = self.history_rates();
+        let rates = &rates[rates.len().saturating_sub(width)..];
+        " ".repeat(width - rates.len()) + &format::sparkline(rates)
+    }
+
+    /// Refresh bar and print a summary report of recorded history below it.
+    /// Report includes total time, minimum, average and maximum rate along with a sparkline of throughput.
+    /// History must be enabled using [with_history](Self::with_history).
+    pub fn finish_report(&mut self) {
+        self.refresh();
+        let report = self.report();
+        self.writer.print(format_args!("\n{}\n", report));
+    }
+
+    /// Returns summary report of recorded history, see [finish_report](Self::finish_report).
+    pub fn report(&mut self) -> String {
+        let rates = self.history_rates();
+        let elapsed_time = self.elapsed_time();
+        let mut report = format!(
+            "total time: {}, {}: {}",
+            format::format_interval(elapsed_time as usize, false),
+            self.unit,
+            self.fmt_counter()
+        );
+
+        if !rates.is_empty() {
+            let min = rates.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = rates.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let avg = self.counter as f64 / elapsed_time as f64;
+            let buckets = rates
+                .chunks(rates.len().div_ceil(40))
+                .map(|x| x.iter().sum::<f64>() / x.len() as f64)
+                .collect::<Vec<f64>>();
+
+            report += &format!(
+                "\nrate: min {}, avg {}, max {}\nthroughput: {}",
+                self.fmt_rate_value(min as f32),
+                self.fmt_rate_value(avg as f32),
+                self.fmt_rate_value(max as f32),
+                format::sparkline(&buckets)
+            );
+        }
+
+        report
+    }
+
     /// Adjust number of columns for bar animation using length of remanining bar.
     pub(crate) fn adjust_ncols(&mut self, lbar_rbar_len: i16) {
         if self.dynamic_ncols || (lbar_rbar_len + self.ncols != self.bar_length) {
@@ -383,10 +1653,19 @@ impl Bar {
                 self.ncols = ncols;
             } else {
                 let columns = crate::term::get_columns_or(0);
+                self.columns = Some(columns);
 
                 if columns != 0 {
                     let new_ncols = columns as i16 - lbar_rbar_len;
                     self.ncols = if new_ncols > 0 { new_ncols } else { 0 };
+
+                    if let Some(max_ncols) = self.max_ncols {
+                        self.ncols = self.ncols.min(max_ncols);
+                    }
+
+                    if let Some(min_ncols) = self.min_ncols {
+                        self.ncols = self.ncols.max(min_ncols);
+                    }
                 } else {
                     self.ncols = 10;
 
@@ -398,49 +1677,316 @@ impl Bar {
         }
     }
 
-    /// Print a string in position of bar.
-    pub(crate) fn write_at(&self, text: String) {
-        if self.position == 0 {
-            self.writer.print(format_args!("\r{}", text));
-        } else {
-            self.writer.print(format_args!(
-                "{}{}\x1b[{}A",
-                "\n".repeat(self.position as usize),
-                text,
-                self.position
-            ));
+    /// Display a rendered string using bar's renderer.
+    pub(crate) fn try_write_at(&self, text: &str) -> std::io::Result<()> {
+        self.renderer.render(self, text)
+    }
+
+    /// Returns an empty frame buffer, reusing allocation of previously rendered frame.
+    fn take_frame(&mut self) -> String {
+        let mut frame = std::mem::take(&mut self.frame);
+        frame.clear();
+        frame
+    }
+
+    /// Keep allocation of a displayed frame, so that it can be reused by next render.
+    pub(crate) fn recycle_frame(&mut self, frame: String) {
+        self.frame = frame;
+    }
+
+    /// Clear current bar display, returning io errors if any.
+    pub(crate) fn try_clear(&self) -> std::io::Result<()> {
+        if self.writer.is_null() || self.heartbeat.is_some() {
+            return Ok(());
         }
+
+        self.renderer.clear(self)?;
+
+        if self.taskbar_progress && self.position == 0 {
+            self.writer.try_print(format_args!("{}", TASKBAR_RESET))?;
+        }
+
+        Ok(())
     }
 
     // -----------------------------------------------------------------------------------------
     // FORMATTING (FOR INTERNAL USE ONLY)
     // -----------------------------------------------------------------------------------------
 
+    /// Returns `OSC 9;4` escape sequence for reporting progress to terminal, if enabled.
+    /// Progress is removed once completed and is shown as indeterminate if total is unknown.
+    pub(crate) fn fmt_taskbar_progress(&self) -> String {
+        if !self.taskbar_progress {
+            "".to_owned()
+        } else if self.completed() {
+            TASKBAR_RESET.to_owned()
+        } else if self.indefinite() {
+            "\x1b]9;4;3;0\x07".to_owned()
+        } else if self.stalled {
+            format!(
+                "\x1b]9;4;4;{}\x07",
+                (self.clamped_percentage() * 100.0) as usize
+            )
+        } else {
+            format!(
+                "\x1b]9;4;1;{}\x07",
+                (self.clamped_percentage() * 100.0) as usize
+            )
+        }
+    }
+
+    /// Returns ANSI trimmed description truncated (or scrolled) to `width`.
+    fn truncate_desc(&self, width: usize) -> String {
+        if self.desc_truncate == format::Truncate::Marquee {
+            format::marquee(&self.desc.trim_ansi(), width, self.marquee_offset)
+        } else {
+            format::truncate(&self.desc.trim_ansi(), width, self.desc_truncate)
+        }
+    }
+
+    /// Returns description with `: ` suffix, truncated to `desc_width`
+    /// or to fit in terminal width along with `rest_len` columns of remaining bar.
+    pub(crate) fn fmt_desc(&self, rest_len: usize) -> String {
+        if self.desc.is_empty() {
+            return "".to_owned();
+        }
+
+        let desc = self.fit_desc(rest_len + 2);
+
+        if desc.is_empty() {
+            "".to_owned()
+        } else {
+            format!("{}: ", desc)
+        }
+    }
+
+    /// Returns description truncated to `desc_width` and to fit along with `rest_len` columns in terminal width,
+    /// terminal width is reused from last [adjust_ncols](Self::adjust_ncols) call.
+    fn fit_desc(&self, rest_len: usize) -> Cow<'_, str> {
+        let desc_len = self.desc.len_ansi();
+        let mut width = self.desc_width;
+        let columns = self.columns.unwrap_or(0) as usize;
+
+        if columns != 0 && desc_len + rest_len > columns {
+            let available = columns.saturating_sub(rest_len);
+            width = Some(width.map_or(available, |x| x.min(available)));
+        }
+
+        match width {
+            Some(width) if desc_len > width => Cow::Owned(self.truncate_desc(width)),
+            _ => Cow::Borrowed(self.desc.as_ref()),
+        }
+    }
+
+    /// Returns number of columns by which description of full width along with `rest_len` columns of remaining bar
+    /// overflows terminal width.
+    fn layout_overflow(&self, rest_len: usize) -> usize {
+        let columns = self.columns.unwrap_or(0) as usize;
+
+        if columns == 0 {
+            return 0;
+        }
+
+        let desc_len = if self.desc.is_empty() {
+            0
+        } else {
+            self.desc_width
+                .map_or(self.desc.len_ansi(), |x| x.min(self.desc.len_ansi()))
+                + 2
+        };
+
+        (desc_len + rest_len).saturating_sub(columns)
+    }
+
+    /// Returns right part of bar i.e. ` 10/100 [00:01<00:09, 10.00it/s, loss=0.42]`.
+    fn fmt_rbar(&self, postfix: &str) -> String {
+        format!(
+            " {}/{}{} [{}<{}, {}{}]",
+            self.fmt_counter(),
+            self.fmt_total(),
+            self.fmt_secondary(),
+            self.fmt_elapsed_time(),
+            self.fmt_remaining_time(),
+            self.fmt_rate(),
+            postfix,
+        )
+    }
+
+    /// Returns right part of bar along with `lbar_len` columns of left part,
+    /// where postfix is truncated to fit in terminal width if description has layout priority.
+    fn fmt_rbar_fit(&self, lbar_len: usize) -> String {
+        let postfix = self.fmt_postfix();
+        let rbar = self.fmt_rbar(&postfix);
+
+        if self.layout_priority == LayoutPriority::Desc && !postfix.is_empty() {
+            let overflow = self.layout_overflow(lbar_len + rbar.len_ansi());
+
+            if overflow > 0 {
+                let width = postfix.len_ansi().saturating_sub(overflow);
+                let postfix = if width > 2 {
+                    format::truncate(&postfix.trim_ansi(), width, format::Truncate::Right)
+                } else {
+                    "".to_owned()
+                };
+
+                return self.fmt_rbar(&postfix);
+            }
+        }
+
+        rbar
+    }
+
+    /// Returns right part of bar which fits in terminal width along with `lbar_len` columns of left part.
+    /// Components are dropped in priority order: rate, remaining time and then counter.
+    fn fmt_narrow_rbar(&self, lbar_len: usize) -> String {
+        let counter = format!(
+            "{}/{}{}",
+            self.fmt_counter(),
+            self.fmt_total(),
+            self.fmt_secondary()
+        );
+        let candidates = [
+            format!(
+                " {} [{}<{}, {}{}]",
+                counter,
+                self.fmt_elapsed_time(),
+                self.fmt_remaining_time(),
+                self.fmt_rate(),
+                self.fmt_postfix(),
+            ),
+            format!(
+                " {} [{}<{}]",
+                counter,
+                self.fmt_elapsed_time(),
+                self.fmt_remaining_time()
+            ),
+            format!(" {}", counter),
+        ];
+        let columns = self.columns.unwrap_or(0) as usize;
+
+        if columns == 0 {
+            return candidates[0].clone();
+        }
+
+        candidates
+            .into_iter()
+            .find(|x| lbar_len + x.len_ansi() <= columns)
+            .unwrap_or_default()
+    }
+
+    /// Returns state symbol followed by a space if `accessible` is true.
+    fn fmt_state(&self) -> String {
+        if !self.accessible {
+            "".to_owned()
+        } else if self.completed() {
+            "\u{2714} ".to_owned()
+        } else {
+            "\u{25B6} ".to_owned()
+        }
+    }
+
+    /// Returns single line status used by [ci_heartbeat](Self::ci_heartbeat).
+    fn fmt_heartbeat(&self) -> String {
+        let mut line = String::new();
+
+        if !self.desc.is_empty() {
+            line += &self.desc;
+            line += ": ";
+        }
+
+        line += &format!("[{}] ", self.fmt_elapsed_time());
+
+        if !self.indefinite() {
+            line += &format!(
+                "{:.0}% {}/{}{} ",
+                self.clamped_percentage() * 100.0,
+                self.fmt_counter(),
+                self.fmt_total(),
+                self.fmt_secondary()
+            );
+        } else if self.secondary.is_some() {
+            line += &format!("{}{} ", self.fmt_counter(), self.fmt_secondary());
+        } else {
+            line += &format!("{}{} ", self.fmt_counter(), self.unit);
+        }
+
+        line += &self.fmt_rate();
+        line
+    }
+
     pub(crate) fn fmt_percentage(&self, precision: usize) -> String {
         format!(
             "{:1$.2$}%",
-            self.percentage() * 100.0,
+            self.clamped_percentage() * 100.0,
             if precision == 0 { 3 } else { precision + 4 },
             precision
         )
     }
 
     pub(crate) fn fmt_counter(&self) -> String {
-        if self.unit_scale {
-            format::format_sizeof(self.counter as f64, self.unit_divisor as f64)
-        } else {
-            format!("{}", self.counter)
+        if !self.indefinite() && self.counter > self.total {
+            match self.overflow {
+                Overflow::Clamp => return self.fmt_number(self.total),
+                Overflow::Extend => {}
+                Overflow::Show => {
+                    return format!(
+                        "{}+{}",
+                        self.fmt_number(self.total),
+                        self.fmt_number(self.counter - self.total)
+                    )
+                }
+            }
         }
+
+        self.fmt_number(self.counter)
     }
 
     pub(crate) fn fmt_total(&self) -> String {
-        if self.unit_scale {
-            format::format_sizeof(self.total as f64, self.unit_divisor as f64)
+        self.fmt_number(self.total)
+    }
+
+    fn fmt_number(&self, num: usize) -> String {
+        if let Some(decimals) = self.decimals {
+            let num = num as f64 / self.scale() as f64;
+
+            if self.unit_scale {
+                format::format_sizeof_with(num, self.unit_divisor as f64, &self.sizeof_options)
+            } else {
+                format!("{:.*}", decimals, num)
+            }
+        } else if self.unit_scale {
+            format::format_sizeof_with(num as f64, self.unit_divisor as f64, &self.sizeof_options)
+        } else if let Some(separator) = &self.thousands_separator {
+            format::format_grouped(num, separator)
         } else {
-            format!("{}", self.total)
+            format!("{}", num)
         }
     }
 
+    /// Returns number of counter units per displayed unit, which is `10^decimals` for fractional counters.
+    fn scale(&self) -> f32 {
+        10_f32.powi(self.decimals.unwrap_or(0) as i32)
+    }
+
+    /// Returns blank text which clears bar.
+    fn fmt_blank(&self) -> String {
+        format!(
+            "{}\r",
+            " ".repeat(crate::term::get_columns_or(self.bar_length as u16) as usize)
+        )
+    }
+
+    /// Returns `summary` of completed bar with placeholders replaced, see [OnComplete](crate::OnComplete).
+    fn fmt_summary(&self, summary: &str) -> String {
+        summary
+            .replace("{desc}", &self.desc)
+            .replace("{count}", &self.fmt_counter())
+            .replace("{total}", &self.fmt_total())
+            .replace("{unit}", &self.unit)
+            .replace("{elapsed}", &self.fmt_elapsed_time())
+            .replace("{rate}", &self.fmt_rate())
+    }
+
     pub(crate) fn fmt_elapsed_time(&self) -> String {
         format::format_interval(self.elapsed_time as usize, false)
     }
@@ -449,15 +1995,126 @@ impl Bar {
         if self.counter == 0 || self.indefinite() {
             "inf".to_owned()
         } else {
-            format::format_interval(self.remaining_time() as usize, false)
+            let remaining_time = self.remaining_time();
+
+            match self.eta {
+                Eta::Remaining => format::format_eta(remaining_time, false),
+                Eta::Finish => format!("ETA {}", format::format_finish_time(remaining_time)),
+                Eta::Both => format!(
+                    "{} (ETA {})",
+                    format::format_eta(remaining_time, false),
+                    format::format_finish_time(remaining_time)
+                ),
+            }
+        }
+    }
+
+    /// Returns bar colour, which is `stall_colour` if progress is stalled.
+    pub(crate) fn fmt_colour(&self) -> String {
+        if self.stalled {
+            return self.stall_colour.clone();
+        }
+
+        let progress = self.clamped_percentage() as f32;
+
+        self.colour_map
+            .iter()
+            .rev()
+            .find(|(threshold, _)| *threshold <= progress)
+            .map(|(_, colour)| colour.clone())
+            .unwrap_or_else(|| self.colour.clone())
+    }
+
+    /// Returns postfix with stall duration suffix, if progress is stalled.
+    pub(crate) fn fmt_postfix(&self) -> Cow<'_, str> {
+        if self.failed.is_none() && !self.stalled && !self.details {
+            return Cow::Borrowed(&self.postfix);
+        }
+
+        let status = match self.failed {
+            Some(failed) => format!(
+                ", ok={}, err={}",
+                self.counter.saturating_sub(failed),
+                failed
+            ),
+            None => "".to_owned(),
+        };
+
+        let mut postfix = status + &self.postfix;
+
+        if self.stalled {
+            postfix += &self.fmt_stall();
         }
+
+        if self.details {
+            postfix += &self.fmt_details();
+        }
+
+        Cow::Owned(postfix)
     }
 
+    /// Returns detailed stats, i.e. `, avg 12.00it/s, min 10.00it/s, max 15.00it/s ▁▂▅█, p50=12.0ms p95=30.1ms p99=41.5ms`.
+    fn fmt_details(&self) -> String {
+        let mut details = String::new();
+
+        if self.elapsed_time > 0.0 {
+            details += &format!(", avg {}", self.fmt_rate_value(self.rate()));
+        }
+
+        let rates = self.history_rates();
+
+        if !rates.is_empty() {
+            details += &format!(
+                ", min {}, max {} {}",
+                self.fmt_rate_value(rates.iter().cloned().fold(f64::INFINITY, f64::min) as f32),
+                self.fmt_rate_value(rates.iter().cloned().fold(f64::NEG_INFINITY, f64::max) as f32),
+                format::sparkline(&rates[rates.len().saturating_sub(8)..])
+            );
+        }
+
+        if let Some(latency) = &self.latency {
+            details += ", ";
+            details += &latency.postfix();
+        }
+
+        details
+    }
+
+    /// Returns stall duration suffix, like ` (stalled 32s)`.
+    pub(crate) fn fmt_stall(&self) -> String {
+        format!(
+            " (stalled {}s)",
+            (self.elapsed_time - self.last_progress.1) as usize
+        )
+    }
+
+    /// Returns secondary counter prefixed with unit of bar i.e. ` rec, 58.0MB`, if secondary counter is attached.
+    fn fmt_secondary(&self) -> String {
+        match &self.secondary {
+            Some(secondary) => format!(
+                " {}, {}",
+                self.unit,
+                secondary.fmt_counter(&self.sizeof_options)
+            ),
+            None => "".to_owned(),
+        }
+    }
+
+    /// Returns rate of bar, which is rate of secondary counter if it is attached.
     pub(crate) fn fmt_rate(&self) -> String {
-        let rate = self.rate();
-        if self.counter == 0 {
+        if let Some(secondary) = &self.secondary {
+            secondary.fmt_rate(self.elapsed_time, &self.sizeof_options)
+        } else if self.counter == 0 {
             format!("?{}/s", self.unit)
-        } else if self.inverse_unit && rate < 1. {
+        } else {
+            self.fmt_rate_value(self.rate())
+        }
+    }
+
+    pub(crate) fn fmt_rate_value(&self, rate: f32) -> String {
+        let rate = rate / self.scale();
+
+        if self.inverse_unit && rate < 1. {
             format!(
                 "{}/{}",
                 if self.unit_scale {
@@ -471,7 +2128,11 @@ impl Bar {
             format!(
                 "{}{}/s",
                 if self.unit_scale {
-                    format::format_sizeof(rate as f64, self.unit_divisor as f64)
+                    format::format_sizeof_with(
+                        rate as f64,
+                        self.unit_divisor as f64,
+                        &self.sizeof_options,
+                    )
                 } else {
                     format!("{:.2}", rate)
                 },
@@ -479,79 +2140,89 @@ impl Bar {
             )
         }
     }
-}
 
-impl BarExt for Bar {
-    fn clear(&mut self) {
-        self.write_at(format!(
-            "\r{}",
-            " ".repeat(crate::term::get_columns_or(self.bar_length as u16) as usize)
-        ));
-    }
+    /// Returns rendered text of bar where lines of description and postfix after first one are
+    /// moved below bar, so that only first lines are used for fitting bar in terminal.
+    fn render_rows(&mut self) -> String {
+        if self.extra_lines == 0 {
+            return self.render_frame();
+        }
 
-    fn input<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error> {
-        self.clear();
-        self.writer.print_str(&text.into());
+        let (desc, postfix) = (self.desc.clone(), self.postfix.clone());
+        let mut rows = vec![];
 
-        let mut input_string = String::new();
-        std::io::stdin().read_line(&mut input_string)?;
-
-        if self.leave {
-            self.refresh();
+        for field in [&mut self.desc, &mut self.postfix] {
+            if let Some((head, tail)) = field.split_once('\n') {
+                rows.push(tail.to_owned());
+                *field = Cow::Owned(head.trim_end_matches(", ").to_owned());
+            }
         }
 
-        Ok(input_string)
-    }
+        let mut text = self.render_frame();
+        self.desc = desc;
+        self.postfix = postfix;
 
-    fn refresh(&mut self) {
-        if !self.force_refresh {
-            self.force_refresh = true;
-            self.update(0);
-            self.force_refresh = false;
-        } else {
-            self.update(0);
+        for row in rows {
+            text.push('\n');
+            text.push_str(&row);
         }
+
+        text
     }
 
-    fn render(&mut self) -> String {
+    /// Returns rendered text of bar, render hooks are not called.
+    fn render_frame(&mut self) -> String {
         self.elapsed_time();
+        self.check_stall();
+        self.check_notify();
+
+        if self.desc_truncate == format::Truncate::Marquee {
+            self.marquee_offset = self.marquee_offset.wrapping_add(1);
+        }
+
+        // Terminal width is cached by `adjust_ncols`, it is only queried here before first adjustment.
+        if self.columns.is_none() {
+            self.columns = Some(crate::term::get_columns_or(0));
+        }
+
+        if !self.indefinite() && self.percentage() >= 1.0 && self.overflow == Overflow::Extend {
+            self.total = self.counter;
+        }
+
+        if self.completed() {
+            match &self.on_complete {
+                OnComplete::Clear if self.position != 0 => return self.fmt_blank(),
+                OnComplete::Keep | OnComplete::Clear => {}
+                OnComplete::ReplaceWith(summary) => return self.fmt_summary(summary),
+            }
+        }
 
         #[cfg(feature = "template")]
         if self.bar_format.is_some() {
             let mut bar_format = self.bar_format.as_ref().unwrap().clone();
 
-            bar_format.replace_with_callback("desc", &self.desc, |fmtval, placeholder| {
-                if !self.desc.is_empty() {
-                    fmtval
-                        + &placeholder
-                            .attr("suffix")
-                            .unwrap_or_else(|| ": ".to_owned())
-                } else {
-                    fmtval
-                }
-            });
-
             bar_format.replace_from_callback("percentage", |placeholder| {
-                placeholder.format_spec.format(self.percentage() * 100.0)
+                placeholder
+                    .format_spec
+                    .format(self.clamped_percentage() * 100.0)
             });
 
             bar_format.replace_from_callback("count", |placeholder| {
-                if self.unit_scale {
-                    placeholder.format_spec.format(format::format_sizeof(
-                        self.counter as f64,
-                        self.unit_divisor as f64,
-                    ))
+                if self.unit_scale
+                    || self.thousands_separator.is_some()
+                    || self.decimals.is_some()
+                    || (!self.indefinite() && self.counter > self.total)
+                {
+                    placeholder.format_spec.format(self.fmt_counter())
                 } else {
                     placeholder.format_spec.format(&self.counter)
                 }
             });
 
             bar_format.replace_from_callback("total", |placeholder| {
-                if self.unit_scale {
-                    placeholder.format_spec.format(format::format_sizeof(
-                        self.total as f64,
-                        self.unit_divisor as f64,
-                    ))
+                if self.unit_scale || self.thousands_separator.is_some() || self.decimals.is_some()
+                {
+                    placeholder.format_spec.format(self.fmt_total())
                 } else {
                     placeholder.format_spec.format(&self.total)
                 }
@@ -571,6 +2242,24 @@ impl BarExt for Bar {
                     ))
             });
 
+            bar_format.replace_from_callback("total_elapsed", |placeholder| {
+                let human = placeholder
+                    .attr("human")
+                    .unwrap_or_else(|| "false".to_owned())
+                    .parse::<bool>()
+                    .unwrap_or(false);
+                placeholder
+                    .format_spec
+                    .format(crate::format::format_interval(
+                        self.total_elapsed_time() as usize,
+                        human,
+                    ))
+            });
+
+            bar_format.replace_from_callback("repeats", |placeholder| {
+                placeholder.format_spec.format(self.repeats)
+            });
+
             bar_format.replace_from_callback("remaining", |placeholder| {
                 if self.indefinite() {
                     placeholder.format_spec.format("inf")
@@ -582,42 +2271,127 @@ impl BarExt for Bar {
                         .unwrap_or(false);
                     placeholder
                         .format_spec
-                        .format(crate::format::format_interval(
-                            self.remaining_time() as usize,
-                            human,
-                        ))
+                        .format(crate::format::format_eta(self.remaining_time(), human))
                 }
             });
 
             bar_format.replace_from_callback("rate", |placeholder| {
-                placeholder.format_spec.format(self.rate())
+                placeholder.format_spec.format(self.rate() / self.scale())
             });
 
-            bar_format.replace("unit", &self.unit);
-            bar_format.replace("postfix", &self.postfix);
+            let (secondary, secondary_rate) = match &self.secondary {
+                Some(secondary) => (
+                    secondary.fmt_counter(&self.sizeof_options),
+                    secondary.fmt_rate(self.elapsed_time, &self.sizeof_options),
+                ),
+                None => ("".to_owned(), "".to_owned()),
+            };
+
+            bar_format.replace_from_callback("secondary", |placeholder| {
+                placeholder.format_spec.format(&secondary)
+            });
+
+            bar_format.replace_from_callback("secondary_rate", |placeholder| {
+                placeholder.format_spec.format(&secondary_rate)
+            });
+
+            bar_format.replace_from_callback("sparkline", |placeholder| {
+                let width = placeholder
+                    .attr("width")
+                    .unwrap_or_else(|| "10".to_owned())
+                    .parse::<usize>()
+                    .unwrap_or(10);
+                placeholder.format_spec.format(self.fmt_sparkline(width))
+            });
+
+            // Samples are only sorted if a percentile placeholder is present and then shared between them.
+            let latency = std::cell::OnceCell::new();
+
+            for (name, fraction) in [("p50", 0.5), ("p95", 0.95), ("p99", 0.99)] {
+                bar_format.replace_from_callback(name, |placeholder| {
+                    let sorted = latency.get_or_init(|| {
+                        self.latency
+                            .as_ref()
+                            .map(Latency::sorted)
+                            .unwrap_or_default()
+                    });
+                    placeholder
+                        .format_spec
+                        .format(Latency::fmt_percentile(sorted, fraction))
+                });
+            }
+
+            #[cfg(feature = "sysinfo")]
+            {
+                let (cpu, memory) = crate::term::process_usage();
+
+                bar_format.replace_from_callback("cpu", |placeholder| {
+                    placeholder.format_spec.format(cpu)
+                });
+
+                bar_format.replace_from_callback("memory", |placeholder| {
+                    placeholder
+                        .format_spec
+                        .format(format::format_sizeof(memory as f64, 1024.) + "B")
+                });
+            }
+
+            bar_format.replace_from_callback("unit", |placeholder| {
+                format::format_ansi(&self.unit, |x| placeholder.format_spec.format(x))
+            });
+
+            let postfix = self.fmt_postfix();
+            bar_format.replace_from_callback("postfix", |placeholder| {
+                format::format_ansi(&postfix, |x| placeholder.format_spec.format(x))
+            });
 
             #[cfg(feature = "spinner")]
-            bar_format.replace_from_callback("spinner", |_| {
+            bar_format.replace_from_callback("spinner", |placeholder| {
                 if let Some(spinner) = &self.spinner {
-                    spinner.render_frame(self.elapsed_time)
+                    format::format_ansi(&spinner.render_frame(self.elapsed_time), |x| {
+                        placeholder.format_spec.format(x)
+                    })
                 } else {
-                    "".to_owned()
+                    placeholder.format_spec.format("")
+                }
+            });
+
+            let spinner = self.animation.fmt_spinner(self.elapsed_time);
+
+            // Description is replaced last, so that it can be truncated to fit along with rest of text in terminal width.
+            let mut rest = bar_format.clone();
+            rest.replace_from_callback("desc", |_| String::new());
+            let rest_len = (rest.unchecked_text().len_ansi() + spinner.len_ansi())
+                .saturating_sub(11)
+                + self.min_ncols.unwrap_or(0).max(0) as usize;
+
+            bar_format.replace_from_callback("desc", |placeholder| {
+                if !self.desc.is_empty() {
+                    let suffix = placeholder
+                        .attr("suffix")
+                        .unwrap_or_else(|| ": ".to_owned());
+                    let desc = format!("{}{}", self.fit_desc(rest_len + suffix.len_ansi()), suffix);
+                    format::format_ansi(&desc, |x| placeholder.format_spec.format(x))
+                } else {
+                    placeholder.format_spec.format("")
                 }
             });
 
-            let length = bar_format.unchecked_text().len_ansi() as i16;
-            self.adjust_ncols(length - 11);
+            let length = bar_format.unchecked_text().len_ansi() + spinner.len_ansi();
+            self.adjust_ncols(length as i16 - 11);
+
+            let colour = self.fmt_colour();
 
             bar_format.replace_from_callback("animation", |_| {
-                let fmtval = self
-                    .animation
-                    .progress(self.percentage() as f32, self.ncols);
+                let fmtval = spinner.clone()
+                    + &self
+                        .animation
+                        .progress(self.clamped_percentage() as f32, self.ncols);
 
-                if self.colour.to_lowercase().starts_with("gradient(") {
+                if colour.to_lowercase().starts_with("gradient(") {
                     #[cfg(feature = "gradient")]
                     return fmtval.gradient_text(
-                        &self
-                            .colour
+                        &colour
                             .to_lowercase()
                             .trim_start_matches("gradient(")
                             .trim_end_matches(')')
@@ -628,8 +2402,8 @@ impl BarExt for Bar {
 
                     #[cfg(not(feature = "gradient"))]
                     panic!("Enable cargo feature `gradient` to use gradient colours.");
-                } else if self.colour != "default" {
-                    return fmtval.colorize(&self.colour);
+                } else if colour != "default" {
+                    return fmtval.colorize(&colour);
                 }
 
                 fmtval
@@ -638,69 +2412,197 @@ impl BarExt for Bar {
             return bar_format.text().unwrap();
         }
 
-        let desc = if self.desc.is_empty() {
-            "".to_owned()
-        } else {
-            format!("{}: ", self.desc)
-        };
-
         if self.indefinite() {
-            let bar = format!(
-                "{}{}{} [{}, {}{}]",
-                desc,
-                self.fmt_counter(),
-                self.unit,
+            let counter = if self.secondary.is_some() {
+                format!("{}{}", self.fmt_counter(), self.fmt_secondary())
+            } else {
+                format!("{}{}", self.fmt_counter(), self.unit)
+            };
+            let rbar = format!(
+                " [{}, {}{}]",
                 self.fmt_elapsed_time(),
                 self.fmt_rate(),
-                self.postfix
+                self.fmt_postfix()
             );
+            let counter = self.fmt_state() + &counter;
+            let desc = self.fmt_desc(counter.len_ansi() + rbar.len_ansi());
+
+            if !self.get_leave() && self.position != 0 {
+                return self.fmt_blank();
+            }
+
+            let mut frame = self.take_frame();
+            frame.push_str(&desc);
+            frame.push_str(&counter);
 
-            if !self.leave && self.position != 0 {
-                return format!(
-                    "{}\r",
-                    " ".repeat(crate::term::get_columns_or(self.bar_length as u16) as usize)
+            if self.bounce {
+                self.adjust_ncols(
+                    (desc.len_ansi()
+                        + counter.len_ansi()
+                        + rbar.len_ansi()
+                        + self.animation.spaces() as usize) as i16,
                 );
+
+                if self.ncols > 0 {
+                    frame.push_str(&self.animation.fmt_spinner(self.elapsed_time));
+                    frame.push_str(&self.animation.fmt_bounce(
+                        self.elapsed_time,
+                        self.ncols,
+                        &self.fmt_colour(),
+                    ));
+                }
+            }
+
+            frame.push_str(&rbar);
+            return frame;
+        }
+
+        let progress = self.clamped_percentage() as f32;
+
+        let percentage = self.fmt_state() + &self.fmt_percentage(0);
+        let reserved =
+            self.min_ncols.unwrap_or(0).max(0) as usize + self.animation.spaces() as usize;
+        let rbar = if self.wrap_lines {
+            self.fmt_narrow_rbar(percentage.len_ansi())
+        } else {
+            self.fmt_rbar_fit(percentage.len_ansi() + reserved)
+        };
+        let desc = self.fmt_desc(percentage.len_ansi() + rbar.len_ansi() + reserved);
+
+        self.adjust_ncols(
+            (desc.len_ansi()
+                + percentage.len_ansi()
+                + rbar.len_ansi()
+                + self.animation.spaces() as usize) as i16,
+        );
+
+        let mut frame = self.take_frame();
+        frame.push_str(&desc);
+        frame.push_str(&percentage);
+
+        if self.ncols > 0 {
+            frame.push_str(&self.animation.fmt_spinner(self.elapsed_time));
+
+            match self.failed {
+                Some(failed) if !self.stalled => {
+                    frame.push_str(&self.animation.fmt_progress_split(
+                        progress,
+                        self.counter.saturating_sub(failed) as f32 / self.total as f32,
+                        self.ncols,
+                        ("green", "red"),
+                    ));
+                }
+                _ => self.animation.write_fmt_progress(
+                    &mut frame,
+                    progress,
+                    self.ncols,
+                    &self.fmt_colour(),
+                ),
             }
+        }
+
+        frame.push_str(&rbar);
+        frame
+    }
+}
+
+impl AsRef<Bar> for Bar {
+    fn as_ref(&self) -> &Bar {
+        self
+    }
+}
+
+impl AsMut<Bar> for Bar {
+    fn as_mut(&mut self) -> &mut Bar {
+        self
+    }
+}
+
+impl BarExt for Bar {
+    fn clear(&mut self) {
+        self.try_clear().unwrap();
+    }
+
+    fn input<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error> {
+        self.clear();
+        self.writer.print_str(&text.into());
+
+        let mut input_string = String::new();
+        std::io::stdin().read_line(&mut input_string)?;
+
+        if self.get_leave() {
+            self.refresh();
+        }
+
+        Ok(input_string)
+    }
+
+    fn input_masked<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error> {
+        self.clear();
+        self.writer.print_str(&text.into());
+
+        let input_string = crate::term::read_line_masked()?;
+
+        if self.get_leave() {
+            self.refresh();
+        }
+
+        Ok(input_string)
+    }
 
-            return bar;
+    fn input_timeout<T: Into<String>>(
+        &mut self,
+        text: T,
+        timeout: std::time::Duration,
+    ) -> Result<Option<String>, std::io::Error> {
+        self.clear();
+        let writer = self.writer.clone();
+        let bar = self.render();
+        writer.print(format_args!("\r{}\n{}", bar, text.into()));
+
+        let interval = std::time::Duration::from_secs_f32(self.mininterval.max(0.1));
+        let input_string = crate::term::read_line_timeout(timeout, interval, || {
+            let bar = self.render();
+            writer.print(format_args!("\x1b7\x1b[1A\r{}\x1b8", bar));
+        })?;
+
+        if input_string.is_none() {
+            writer.print_str("\n");
         }
 
-        let progress = self.percentage() as f32;
+        if self.get_leave() {
+            self.refresh();
+        }
 
-        if progress >= 1.0 {
-            self.total = self.counter;
+        Ok(input_string)
+    }
 
-            if !self.leave && self.position != 0 {
-                return format!(
-                    "{}\r",
-                    " ".repeat(crate::term::get_columns_or(self.bar_length as u16) as usize)
-                );
-            }
+    fn refresh(&mut self) {
+        if !self.force_refresh {
+            self.force_refresh = true;
+            self.update(0);
+            self.force_refresh = false;
+        } else {
+            self.update(0);
+        }
+    }
+
+    fn render(&mut self) -> String {
+        if let Some(pre_render) = self.pre_render.clone() {
+            (pre_render.0)(self);
         }
 
-        let lbar = desc + &self.fmt_percentage(0);
-        let rbar = format!(
-            " {}/{} [{}<{}, {}{}]",
-            self.fmt_counter(),
-            self.fmt_total(),
-            self.fmt_elapsed_time(),
-            self.fmt_remaining_time(),
-            self.fmt_rate(),
-            self.postfix,
-        );
+        let mut text = self.render_state();
 
-        self.adjust_ncols(
-            (format!("{}{}", lbar, rbar).len_ansi() + self.animation.spaces() as usize) as i16,
-        );
+        if let Some(post_render) = &self.post_render {
+            (post_render.0)(self, &mut text);
+        }
 
-        if self.ncols <= 0 {
-            return lbar + &rbar;
+        if self.ascii {
+            text = ascii_text(&text);
         }
 
-        lbar + &self
-            .animation
-            .fmt_progress(progress, self.ncols, &self.colour)
-            + &rbar
+        text
     }
 
     fn reset(&mut self, total: Option<usize>) {
@@ -709,28 +2611,81 @@ impl BarExt for Bar {
         }
 
         self.counter = self.initial;
-        self.timer = std::time::Instant::now();
+        self.timer = self.clock.now();
+        self.elapsed_offset = std::time::Duration::ZERO;
+        self.elapsed_time = 0.0;
+        self.last_progress = (self.counter, 0.0);
+        self.stalled = false;
+        self.notified = false;
+        self.failed = None;
+        self.cumulative = (0.0, 0);
+        self.repeats = 0;
+        self.marquee_offset = 0;
+        self.render_cost = 0.0;
+        self.postfix = Cow::Borrowed("");
+
+        if self.dynamic_miniters {
+            self.miniters = 0;
+        }
+
+        if let Some((interval, _, _)) = self.heartbeat {
+            self.heartbeat = Some((interval, 0.0, self.counter));
+        }
+
+        if matches!(self.state, BarState::Done | BarState::Failed) {
+            self.set_state(BarState::Running);
+        }
+
+        if let Some(history) = &mut self.history {
+            history.clear();
+        }
+
+        if let Some(latency) = &mut self.latency {
+            latency.clear();
+        }
+
+        if let Some(secondary) = &mut self.secondary {
+            secondary.clear();
+        }
+    }
+
+    fn suspend<F: FnOnce() -> R, R>(&mut self, f: F) -> R {
+        self.clear();
+        self.writer.print_str("\r");
+        let output = f();
+        self.refresh();
+        output
     }
 
-    fn update(&mut self, n: usize) {
+    fn try_update(&mut self, n: usize) -> std::io::Result<bool> {
         if self.trigger(n) {
+            let started = crate::Instant::now();
             let text = self.render();
-            let length = text.len_ansi() as i16;
+            let length = text.split('\n').next().unwrap_or_default().len_ansi() as i16;
 
             if length != self.bar_length {
-                self.clear();
+                self.try_clear()?;
             }
 
             self.bar_length = length;
-            self.write_at(text);
+            self.try_write_at(&text)?;
+            self.recycle_frame(text);
+            self.record_render_cost(started);
+            return Ok(true);
         }
+
+        Ok(false)
+    }
+
+    fn update(&mut self, n: usize) -> bool {
+        self.try_update(n).unwrap()
     }
 
     #[cfg(feature = "writer")]
-    fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T) {
+    fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T) -> bool {
         if self.trigger(n) {
             let text = self.render();
-            let length = text.len_ansi() as i16;
+            let length = text.split('\n').next().unwrap_or_default().len_ansi() as i16;
 
             if length != self.bar_length {
                 self.clear();
@@ -744,25 +2699,41 @@ impl BarExt for Bar {
                 .unwrap();
             writer.flush().unwrap();
             crate::thread::lock::release();
+            return true;
         }
+
+        false
     }
 
-    fn update_to(&mut self, update_to_n: usize) {
+    fn update_to(&mut self, update_to_n: usize) -> bool {
         self.counter = update_to_n;
-        self.update(0);
+        self.update(0)
     }
 
     #[cfg(feature = "writer")]
-    fn update_to_writer<T: std::io::Write>(&mut self, update_to_n: usize, writer: &mut T) {
+    fn update_to_writer<T: std::io::Write>(&mut self, update_to_n: usize, writer: &mut T) -> bool {
         self.counter = update_to_n;
-        self.update_writer(0, writer);
+        self.update_writer(0, writer)
     }
 
     fn write<T: Into<String>>(&mut self, text: T) {
+        self.write_fmt(format_args!("{}", text.into()));
+    }
+
+    fn write_fmt(&mut self, args: std::fmt::Arguments) {
+        self.clear();
+        self.writer.print(format_args!("\r{}\n", args));
+
+        if self.get_leave() {
+            self.refresh();
+        }
+    }
+
+    fn write_to<T: Into<String>>(&mut self, text: T, writer: Writer) {
         self.clear();
-        self.writer.print(format_args!("\r{}\n", text.into()));
+        writer.print(format_args!("\r{}\n", text.into()));
 
-        if self.leave {
+        if self.get_leave() {
             self.refresh();
         }
     }
@@ -782,13 +2753,31 @@ pub struct BarBuilder {
     pb: Bar,
     #[cfg(feature = "template")]
     bar_format: Option<String>,
+    colour_map: Vec<(f32, String)>,
+    position_auto: bool,
 }
 
 impl BarBuilder {
     /// Prefix for the progress bar.
     /// (default: `""`)
     pub fn desc<T: Into<String>>(mut self, desc: T) -> Self {
-        self.pb.desc = desc.into();
+        self.pb.desc = Cow::Owned(desc.into());
+        self
+    }
+
+    /// Maximum display width of `desc`, longer descriptions are truncated with an ellipsis.
+    /// Descriptions are also truncated when they don't fit in terminal width.
+    /// (default: `None`)
+    pub fn desc_width(mut self, desc_width: usize) -> Self {
+        self.pb.desc_width = Some(desc_width);
+        self
+    }
+
+    /// Side from which long descriptions are truncated.
+    /// Use [Marquee](crate::format::Truncate::Marquee) for scrolling long descriptions within `desc_width` instead of truncating them.
+    /// (default: [Right](crate::format::Truncate::Right))
+    pub fn desc_truncate(mut self, desc_truncate: format::Truncate) -> Self {
+        self.pb.desc_truncate = desc_truncate;
         self
     }
 
@@ -802,10 +2791,18 @@ impl BarBuilder {
     }
 
     /// If true, keeps all traces of the progressbar upon termination of iteration.
-    /// If false, will leave only if position is 0.
+    /// If false, progressbar is cleared once it is completed, see [on_complete](Self::on_complete).
     /// (default: `true`)
     pub fn leave(mut self, leave: bool) -> Self {
-        self.pb.leave = leave;
+        self.pb.set_leave(leave);
+        self
+    }
+
+    /// Behaviour of bar once progress is completed, see [OnComplete](crate::OnComplete).
+    /// Overrides `leave` option.
+    /// (default: `OnComplete::Keep`)
+    pub fn on_complete(mut self, on_complete: OnComplete) -> Self {
+        self.pb.on_complete = on_complete;
         self
     }
 
@@ -821,6 +2818,69 @@ impl BarBuilder {
         self
     }
 
+    /// Minimum width of progress meter, when it is resized automatically to fit terminal width.
+    /// Description (or postfix, see [layout_priority](Self::layout_priority)) is truncated to keep meter readable.
+    /// (default: `None`)
+    pub fn min_ncols<T: Into<i16>>(mut self, min_ncols: T) -> Self {
+        self.pb.min_ncols = Some(min_ncols.into());
+        self
+    }
+
+    /// Maximum width of progress meter, when it is resized automatically to fit terminal width.
+    /// This prevents meter from stretching across very wide terminals.
+    /// (default: `None`)
+    pub fn max_ncols<T: Into<i16>>(mut self, max_ncols: T) -> Self {
+        self.pb.max_ncols = Some(max_ncols.into());
+        self
+    }
+
+    /// Text which keeps its space when terminal is too narrow for whole bar.
+    /// (default: [Postfix](crate::LayoutPriority::Postfix))
+    pub fn layout_priority(mut self, layout_priority: LayoutPriority) -> Self {
+        self.pb.layout_priority = layout_priority;
+        self
+    }
+
+    /// Number of rows reserved below bar for multi-line bars, e.g. bar on first row and detailed stats on second row.
+    /// Lines of rendered text after first one are displayed in these rows, lines of description and postfix after
+    /// first one are moved to them. Bars at next positions should skip these rows.
+    /// (default: `0`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100, extra_lines = 1);
+    /// pb.set_postfix("loss=0.42\nepoch 1/10, lr=0.001");
+    /// pb.update(10);
+    ///
+    /// let text = pb.render();
+    /// let rows = text.lines().collect::<Vec<_>>();
+    /// assert!(rows[0].ends_with("it/s, loss=0.42]"));
+    /// assert_eq!(rows[1], "epoch 1/10, lr=0.001");
+    ///
+    /// eprint!("\n\n");
+    /// ```
+    pub fn extra_lines(mut self, extra_lines: u16) -> Self {
+        self.pb.extra_lines = extra_lines;
+        self
+    }
+
+    /// Style of displaying remaining time, countdown or projected finish time or both.
+    /// (default: [Remaining](crate::Eta::Remaining))
+    pub fn eta(mut self, eta: Eta) -> Self {
+        self.pb.eta = eta;
+        self
+    }
+
+    /// Policy for displaying progress when counter exceeds total.
+    /// (default: [Extend](crate::Overflow::Extend))
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.pb.overflow = overflow;
+        self
+    }
+
     /// Minimum progress display update interval (in seconds).
     /// (default: `0.1`)
     pub fn mininterval<T: Into<f32>>(mut self, mininterval: T) -> Self {
@@ -828,6 +2888,31 @@ impl BarBuilder {
         self
     }
 
+    /// Maximum fraction of loop time spent in rendering progress bar (e.g. `0.05` for 5%).
+    /// Rendering time is measured and `mininterval` is increased automatically to stay within this budget,
+    /// which protects tight loops on slow terminals from being dominated by drawing.
+    /// (default: `None`)
+    pub fn render_budget(mut self, render_budget: f32) -> Self {
+        self.pb.render_budget = Some(render_budget);
+        self
+    }
+
+    /// Source of time used for elapsed time, rate, ETA and refresh intervals.
+    /// See [MockClock](crate::MockClock) for testing.
+    /// (default: `SystemClock`)
+    pub fn clock<T: Clock + 'static>(mut self, clock: T) -> Self {
+        self.pb.set_clock(clock);
+        self
+    }
+
+    /// Backend used for displaying rendered progress bar.
+    /// See [term](crate::term) module for available renderers.
+    /// (default: `AnsiRenderer`)
+    pub fn renderer<T: Renderer + 'static>(mut self, renderer: T) -> Self {
+        self.pb.renderer = Arc::new(renderer);
+        self
+    }
+
     /// Minimum progress display update interval, in iterations.
     /// If > 0, will skip display of specified number of iterations. Tweak this and mininterval to get very efficient loops.
     /// If your progress is erratic with both fast and slow iterations (network, skipping items, etc) you should set miniters=1.
@@ -845,6 +2930,7 @@ impl BarBuilder {
     }
 
     /// Whether to disable the entire progress bar wrapper.
+    /// All bars can also be disabled using [set_enabled](crate::set_enabled) or `KDAM_DISABLE=1` environment variable.
     /// (default: `false`)
     pub fn disable(mut self, disable: bool) -> Self {
         self.pb.disable = disable;
@@ -854,7 +2940,7 @@ impl BarBuilder {
     /// String that will be used to define the unit of each iteration.
     /// (default: `"it"`)
     pub fn unit<T: Into<String>>(mut self, unit: T) -> Self {
-        self.pb.unit = unit.into();
+        self.pb.unit = Cow::Owned(unit.into());
         self
     }
 
@@ -866,6 +2952,26 @@ impl BarBuilder {
         self
     }
 
+    /// If true, counter and total are displayed with comma as thousands separator i.e. `1,234,567`.
+    /// Ignored if `unit_scale` is true.
+    /// (default: `false`)
+    pub fn comma_grouping(mut self, comma_grouping: bool) -> Self {
+        self.pb.thousands_separator = if comma_grouping {
+            Some(",".to_owned())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Thousands separator used for displaying counter and total, useful for locale specific separators like `.` or ` `.
+    /// Ignored if `unit_scale` is true.
+    /// (default: `None`)
+    pub fn thousands_separator<T: Into<String>>(mut self, thousands_separator: T) -> Self {
+        self.pb.thousands_separator = Some(thousands_separator.into());
+        self
+    }
+
     /// If true, if the number of iterations per second is less than 1, then the number of seconds per iteration will be used instead.
     /// (default: `false`)
     pub fn inverse_unit(mut self, inverse_unit: bool) -> Self {
@@ -911,15 +3017,47 @@ impl BarBuilder {
     /// |             | description: alternative way to display time. <br>      |                 |
     /// |             | type: bool <br>                                         |                 |
     /// |             | default: false <br>                                     |                 |
+    /// | total_elapsed | name: **human** <br>                                  | &#10004;        |
+    /// |             | description: elapsed time across all repeats. <br>      |                 |
+    /// |             | type: bool <br>                                         |                 |
+    /// |             | default: false <br>                                     |                 |
+    /// | repeats     |                                                         | &#10004;        |
     /// | remaining   | name: **human** <br>                                    | &#10004;        |
     /// |             | description: alternative way to display time. <br>      |                 |
     /// |             | type: bool <br>                                         |                 |
     /// |             | default: false <br>                                     |                 |
     /// | rate        |                                                         | &#10004; (true) |
+    /// | secondary   | description: secondary counter with its unit. <br>     | &#10004;        |
+    /// |             | requires: secondary counter <br>                        |                 |
+    /// | secondary_rate | description: rate of secondary counter. <br>         | &#10004;        |
+    /// |             | requires: secondary counter <br>                        |                 |
+    /// | sparkline   | name: **width** <br>                                    | &#10004;        |
+    /// |             | description: number of recent rate samples. <br>        |                 |
+    /// |             | type: usize <br>                                        |                 |
+    /// |             | default: 10 <br>                                        |                 |
+    /// | p50         | description: median time per iteration. <br>           | &#10004;        |
+    /// |             | requires: latency recording <br>                        |                 |
+    /// | p95         | description: 95th percentile time per iteration. <br>   | &#10004;        |
+    /// |             | requires: latency recording <br>                        |                 |
+    /// | p99         | description: 99th percentile time per iteration. <br>   | &#10004;        |
+    /// |             | requires: latency recording <br>                        |                 |
+    /// | cpu         | description: CPU usage of process in percentage. <br>   | &#10004; (true) |
+    /// |             | requires feature: sysinfo <br>                          |                 |
+    /// | memory      | description: resident memory of process. <br>           | &#10004;        |
+    /// |             | requires feature: sysinfo <br>                          |                 |
     /// | unit        |                                                         | &#10004; (true) |
     /// | postfix     |                                                         | &#10004; (true) |
-    /// | spinner     |                                                         | &#10060;        |
+    /// | spinner     |                                                         | &#10004;        |
     /// | animation   |                                                         | &#10060;        |
+    ///
+    /// ## Alignment
+    ///
+    /// Placeholders with formatting support accept width, alignment and fill of rust format specs,
+    /// so columns don't shift when their values change length. Coloured text is aligned by its display width.
+    ///
+    /// ```text
+    /// {desc:<20}{percentage:>3.0}%|{animation}| {count:>6}/{total} [{rate:>8.2}{unit}/s{postfix}]
+    /// ```
     #[cfg(feature = "template")]
     #[cfg_attr(docsrs, doc(cfg(feature = "template")))]
     pub fn bar_format<T: Into<String>>(mut self, bar_format: T) -> Self {
@@ -942,6 +3080,15 @@ impl BarBuilder {
         self
     }
 
+    /// Formatting options (precision, spacing and prefix names) of values scaled by `unit_scale`,
+    /// e.g. to display `12.3 MB` or `12 MiB` instead of `12.3MB`.
+    /// Ignored unless `unit_scale` is true.
+    /// (default: `SizeofOptions::default()`)
+    pub fn sizeof_options(mut self, sizeof_options: format::SizeofOptions) -> Self {
+        self.pb.sizeof_options = sizeof_options;
+        self
+    }
+
     /// Divide values by this unit_divisor.
     /// Ignored unless `unit_scale` is true.
     /// (default: `1024`)
@@ -950,12 +3097,241 @@ impl BarBuilder {
         self
     }
 
+    /// If no progress is made within this duration, progress bar enters a stalled state.
+    /// Stalled progress bar is displayed in `stall_colour` with a `(stalled 32s)` suffix.
+    /// Use it along with [monitor](crate::monitor) mode, since stall state is only checked while rendering.
+    /// (default: `None`)
+    pub fn stall_timeout(mut self, stall_timeout: std::time::Duration) -> Self {
+        self.pb.stall_timeout = Some(stall_timeout);
+        self
+    }
+
+    /// Notification which is sent once when progress is completed, see [Bar::notify_on_complete](crate::Bar::notify_on_complete).
+    /// (default: `None`)
+    pub fn notify(mut self, notify: Notify) -> Self {
+        self.pb.notify = Some(notify);
+        self
+    }
+
+    /// Colour of progress bar while progress is stalled, see [stall_timeout](Self::stall_timeout).
+    /// (default: `yellow`)
+    pub fn stall_colour<T: Into<String>>(mut self, stall_colour: T) -> Self {
+        self.pb.stall_colour = stall_colour.into();
+        self
+    }
+
+    /// Callback which is called once when progress bar enters a stalled state, see [stall_timeout](Self::stall_timeout).
+    /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, MockClock};
+    /// use std::{sync::mpsc, time::Duration};
+    ///
+    /// let clock = MockClock::default();
+    /// let (sender, receiver) = mpsc::channel();
+    /// let sender = std::sync::Mutex::new(sender);
+    /// let mut pb = tqdm!(
+    ///     total = 100,
+    ///     clock = clock.clone(),
+    ///     stall_timeout = Duration::from_secs(5),
+    ///     stall_colour = "red",
+    ///     on_stall = move |pb: &kdam::Bar| sender.lock().unwrap().send(pb.get_counter()).unwrap()
+    /// );
+    ///
+    /// pb.update(10);
+    /// clock.advance(Duration::from_secs(6));
+    /// pb.refresh();
+    ///
+    /// assert!(pb.is_stalled());
+    /// assert_eq!(receiver.try_recv(), Ok(10));
+    /// ```
+    pub fn on_stall<F: Fn(&Bar) + Send + Sync + 'static>(mut self, on_stall: F) -> Self {
+        self.pb.on_stall = Some(Hook(Arc::new(on_stall)));
+        self
+    }
+
+    /// Hook which is called with previous state, when state of bar is changed, see [BarState](crate::BarState).
+    /// (default: `None`)
+    pub fn on_state_change<F: Fn(&Bar, BarState) + Send + Sync + 'static>(
+        mut self,
+        on_state_change: F,
+    ) -> Self {
+        self.pb.on_state_change = Some(Hook(Arc::new(on_state_change)));
+        self
+    }
+
+    /// Hook which is called before bar is rendered, it can modify bar i.e. mask secrets in description.
+    /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Bar, BarExt};
+    ///
+    /// fn mask(pb: &mut Bar) {
+    ///     let desc = pb.get_desc().replace("hunter2", "*******");
+    ///     pb.set_description(desc);
+    /// }
+    ///
+    /// let mut pb = tqdm!(total = 100, desc = "login hunter2", pre_render = mask);
+    /// assert!(pb.render().starts_with("login *******: "));
+    /// ```
+    pub fn pre_render<F: Fn(&mut Bar) + Send + Sync + 'static>(mut self, pre_render: F) -> Self {
+        self.pb.pre_render = Some(Hook(Arc::new(pre_render)));
+        self
+    }
+
+    /// Hook which is called after bar is rendered, it can modify rendered text i.e. add a timestamp prefix.
+    /// Layout is not adjusted for text added by hook, so it should be kept short.
+    /// (default: `None`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Bar, BarExt};
+    ///
+    /// fn timestamp(pb: &Bar, text: &mut String) {
+    ///     text.insert_str(0, &format!("[{:.1}s] ", pb.elapsed_time));
+    /// }
+    ///
+    /// let mut pb = tqdm!(total = 100, post_render = timestamp);
+    /// assert!(pb.render().starts_with("[0.0s]   0%|"));
+    /// ```
+    pub fn post_render<F: Fn(&Bar, &mut String) + Send + Sync + 'static>(
+        mut self,
+        post_render: F,
+    ) -> Self {
+        self.pb.post_render = Some(Hook(Arc::new(post_render)));
+        self
+    }
+
+    /// If true, a bouncing block animation is displayed when total is unknown (total=0).
+    /// (default: `false`)
+    pub fn bounce(mut self, bounce: bool) -> Self {
+        self.pb.bounce = bounce;
+        self
+    }
+
+    /// If true, progress state is conveyed by symbols in addition to colours.
+    /// Bar is prefixed with `▶` or `✔` symbol and remaining part of bar is filled with a visible pattern,
+    /// see [Animation::accessible](crate::Animation::accessible).
+    /// (default: `false`)
+    pub fn accessible(mut self, accessible: bool) -> Self {
+        self.pb.accessible = accessible;
+        self
+    }
+
+    /// If true, bar is rendered using ASCII characters only and without colours, so that it is readable on
+    /// dumb terminals like serial consoles and minimal containers. [Plain](crate::Animation::Plain) animation
+    /// and ASCII spinner frames are used, any other non ASCII glyph is replaced with `?`.
+    /// Use [term::supports_unicode](crate::term::supports_unicode) for enabling it automatically.
+    /// (default: `false`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100, desc = "café", colour = "green", ascii = true);
+    /// pb.update(50);
+    /// assert!(pb.render().starts_with("caf?:  50%[#####>----] 50/100"));
+    /// ```
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.pb.ascii = ascii;
+        self
+    }
+
+    /// If true, progress is also reported to terminal using `OSC 9;4` escape sequences,
+    /// which is displayed in taskbar / tab by terminals like Windows Terminal, ConEmu and iTerm2.
+    /// It is only enabled if terminal supports it, see [term::supports_taskbar_progress](crate::term::supports_taskbar_progress).
+    /// Only progress bar at position `0` reports progress, reported progress is removed when bar is cleared or dropped.
+    /// (default: `false`)
+    pub fn taskbar_progress(mut self, taskbar_progress: bool) -> Self {
+        self.pb.taskbar_progress = taskbar_progress;
+        self
+    }
+
+    /// If true, progress bar components are dropped in priority order to fit in narrow terminals,
+    /// instead of overflowing terminal width. Components are dropped in order of
+    /// animation, rate, remaining time, counter and description, so that percentage always fits.
+    /// (default: `false`)
+    pub fn wrap_lines(mut self, wrap_lines: bool) -> Self {
+        self.pb.wrap_lines = wrap_lines;
+        self
+    }
+
+    /// Apply options from [BarConfig](crate::BarConfig), options set after this are applied over it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarConfig};
+    ///
+    /// let config: BarConfig = serde_json::from_str(r#"{"desc": "loading", "unit": "B"}"#).unwrap();
+    /// let pb = tqdm!(config = &config, total = 100, desc = "saving");
+    /// assert_eq!(pb.get_desc(), "saving");
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn config(self, config: &crate::BarConfig) -> Self {
+        config.apply(self)
+    }
+
+    /// If true, progress history is recorded, see [Bar::with_history](crate::Bar::with_history).
+    /// (default: `false`)
+    pub fn history(mut self, history: bool) -> Self {
+        self.pb.history = if history {
+            Some(Timeline::default())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Record progress history keeping last `capacity` samples, see [Bar::with_history_capacity](crate::Bar::with_history_capacity).
+    /// (default: disabled)
+    pub fn history_capacity(mut self, capacity: usize) -> Self {
+        self.pb.history = Some(Timeline::new(capacity));
+        self
+    }
+
+    /// If true, time between updates is recorded, see [Bar::with_latency](crate::Bar::with_latency).
+    /// (default: `false`)
+    pub fn latency(mut self, latency: bool) -> Self {
+        self.pb.latency = if latency {
+            Some(Latency::default())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// If true, position is set to next free row from a global registry, see [Bar::with_position_auto](crate::Bar::with_position_auto).
+    /// This overrides `position` option.
+    /// (default: `false`)
+    pub fn position_auto(mut self, position_auto: bool) -> Self {
+        self.position_auto = position_auto;
+        self
+    }
+
     /// Bar colour (e.g. "green", "#00ff00").
     pub fn colour<T: Into<String>>(mut self, colour: T) -> Self {
         self.pb.colour = colour.into();
         self
     }
 
+    /// Bar colours by progress thresholds, see [Bar::colour_map](crate::Bar::colour_map).
+    /// (default: `[]`)
+    pub fn colour_map(mut self, colour_map: &[(f32, &str)]) -> Self {
+        self.colour_map = colour_map
+            .iter()
+            .map(|(threshold, colour)| (*threshold, colour.to_string()))
+            .collect();
+        self
+    }
+
     /// Don't display until few seconds have elapsed.
     /// (default: `0`)
     pub fn delay<T: Into<f32>>(mut self, delay: T) -> Self {
@@ -964,6 +3340,7 @@ impl BarBuilder {
     }
 
     /// Animation style to use with progress bar.
+    /// Custom charsets are checked using [Animation::validate](crate::Animation::validate).
     /// (default: [tqdm](crate::Animation::Tqdm))
     pub fn animation<T: Into<Animation>>(mut self, animation: T) -> Self {
         self.pb.animation = animation.into();
@@ -980,7 +3357,7 @@ impl BarBuilder {
         self
     }
 
-    /// Select writer type to display progress bar output between `stdout` and `stderr`.
+    /// Select writer type to display progress bar output between `stdout` and `stderr`, or discard it using `null`.
     /// (default: [stderr](crate::term::Writer))
     pub fn writer<T: Into<Writer>>(mut self, writer: T) -> Self {
         self.pb.writer = writer.into();
@@ -994,23 +3371,79 @@ impl BarBuilder {
         self
     }
 
-    /// Build [Bar](crate::Bar), this method only returns error when incorrect `bar_format` is used incorrectly.
+    /// Build [Bar](crate::Bar), this method only returns error when `bar_format`, `colour` or `colour_map` is invalid.
     #[allow(unused_mut)]
-    pub fn build(mut self) -> Result<Bar, String> {
+    pub fn build(mut self) -> Result<Bar, crate::Error> {
         #[cfg(feature = "template")]
         if let Some(bar_format) = self.bar_format {
-            self.pb.set_bar_format(bar_format).map_err(|e| e.message())?;
+            self.pb.set_bar_format(bar_format)?;
+        }
+
+        crate::term::Colour::parse(&self.pb.colour)?;
+        crate::term::Colour::parse(&self.pb.stall_colour)?;
+        self.pb.animation.validate()?;
+
+        for (_, colour) in &self.colour_map {
+            crate::term::Colour::parse(colour)?;
+        }
+
+        self.pb.colour_map = self.colour_map;
+        self.pb.colour_map.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        if self.pb.accessible {
+            self.pb.animation = self.pb.animation.accessible();
+        }
+
+        if self.pb.ascii {
+            self.pb.animation = Animation::Plain;
+
+            #[cfg(feature = "spinner")]
+            if let Some(spinner) = &self.pb.spinner {
+                self.pb.spinner = Some(spinner.ascii());
+            }
+        }
+
+        if self.pb.taskbar_progress {
+            self.pb.taskbar_progress = crate::term::supports_taskbar_progress();
+        }
+
+        if self.position_auto {
+            self.pb = self.pb.with_position_auto();
         }
 
         Ok(self.pb.init())
     }
 }
 
+/// Returns `text` without colours and with non ASCII glyphs replaced by ASCII ones, see [BarBuilder::ascii](crate::BarBuilder::ascii).
+fn ascii_text(text: &str) -> String {
+    text.trim_ansi()
+        .chars()
+        .map(|x| match x {
+            '\u{2714}' => '+',
+            '\u{25B6}' => '>',
+            '\u{23F8}' => '=',
+            '\u{2717}' => 'x',
+            '\u{00B7}' | '\u{2026}' => '.',
+            x if x.is_ascii() => x,
+            _ => '?',
+        })
+        .collect()
+}
+
 /// [tqdm](https://github.com/tqdm/tqdm) like macro for constructing [BarIterator](crate::BarIterator) if iterable is given else [Bar](crate::Bar).
 ///
+/// Iterable can be anything which implements [IntoIterator], i.e. ranges, slices, vectors and iterators.
+/// Total is inferred from `size_hint` of iterator, so `total` option is not needed for
+/// [ExactSizeIterator] inputs like `0..n`, `&[T]` and `Vec<T>`.
+///
 /// This macro use [BarBuilder](crate::BarBuilder) for creating [Bar](crate::Bar).
 /// See all available [methods](crate::BarBuilder).
 ///
+/// Each option is a method of [BarBuilder](crate::BarBuilder), so unknown options are reported as compile errors
+/// pointing at the option.
+/// Options are applied in given order, so `config` option (requires `serde` feature) can be overridden by options after it.
+///
 /// # Panics
 ///
 /// This macro will panic if [BarBuilder::build](crate::BarBuilder::build) returns error.
@@ -1026,18 +3459,30 @@ impl BarBuilder {
 /// tqdm!(0..100);
 /// tqdm!(0..100, desc = "0 to 99");
 /// tqdm!(["a", "b", "c", "d"].iter());
+///
+/// let files = vec!["a.txt", "b.txt", "c.txt"];
+/// assert_eq!(tqdm!(&files).pb.get_total(), 3);
+/// assert_eq!(tqdm!(&files[1..]).pb.get_total(), 2);
+/// assert_eq!(tqdm!(files, desc = "files").pb.get_total(), 3);
+/// ```
+///
+/// ```compile_fail
+/// kdam::tqdm!(totl = 100);
 /// ```
 #[macro_export]
 macro_rules! tqdm {
-    ($($setter_method: ident = $value: expr),*) => {
+    ($($setter_method: ident = $value: expr),* $(,)?) => {{
         $crate::BarBuilder::default()$(.$setter_method($value))*.build().unwrap()
-    };
+    }};
 
-    ($iterable: expr) => {
-        $crate::BarIterator::new_with_bar($iterable, kdam::Bar::default())
+    ($iterable: expr $(,)?) => {
+        $crate::BarIterator::new_with_bar(::core::iter::IntoIterator::into_iter($iterable), $crate::Bar::default())
     };
 
-    ($iterable: expr, $($setter_method: ident = $value: expr),*) => {
-        $crate::BarIterator::new_with_bar($iterable, kdam::BarBuilder::default()$(.$setter_method($value))*.build().unwrap())
-    };
+    ($iterable: expr, $($setter_method: ident = $value: expr),* $(,)?) => {{
+        $crate::BarIterator::new_with_bar(
+            ::core::iter::IntoIterator::into_iter($iterable),
+            $crate::BarBuilder::default()$(.$setter_method($value))*.build().unwrap()
+        )
+    }};
 }
@@ -0,0 +1,160 @@
+use super::{Bar, BarExt};
+
+/// Receiving half of a channel which can be decorated with a progress bar.
+///
+/// Implemented for [std::sync::mpsc::Receiver] and `crossbeam_channel::Receiver` (with `crossbeam` feature).
+/// Implement this trait for other channel types to use them with [TqdmReceiver](crate::TqdmReceiver).
+pub trait Channel {
+    /// Type of received messages.
+    type Item;
+
+    /// Block until a message is received, returns `None` if channel is disconnected.
+    fn recv_item(&self) -> Option<Self::Item>;
+
+    /// Returns number of messages waiting in channel, if it can be known.
+    fn queue_depth(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<T> Channel for std::sync::mpsc::Receiver<T> {
+    type Item = T;
+
+    fn recv_item(&self) -> Option<Self::Item> {
+        self.recv().ok()
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<T> Channel for crossbeam_channel::Receiver<T> {
+    type Item = T;
+
+    fn recv_item(&self) -> Option<Self::Item> {
+        self.recv().ok()
+    }
+
+    fn queue_depth(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<R: Channel> Channel for &R {
+    type Item = R::Item;
+
+    fn recv_item(&self) -> Option<Self::Item> {
+        (*self).recv_item()
+    }
+
+    fn queue_depth(&self) -> Option<usize> {
+        (*self).queue_depth()
+    }
+}
+
+/// Iterable version of [Bar](crate::Bar) for channel receivers.
+///
+/// If queue depth of channel is known, it is displayed as postfix like `queue=12`.
+#[derive(Debug)]
+pub struct ReceiverIterator<R> {
+    /// Receiver to decorate with a progress bar.
+    pub receiver: R,
+    /// Instance of [Bar](crate::Bar) to display progress updates for receiver.
+    pub pb: Bar,
+    started: bool,
+}
+
+impl<R: Channel> ReceiverIterator<R> {
+    /// Create a new instance of [ReceiverIterator](crate::ReceiverIterator) from receiver and [Bar](crate::Bar).
+    pub fn new(receiver: R, pb: Bar) -> ReceiverIterator<R> {
+        ReceiverIterator {
+            receiver,
+            pb,
+            started: false,
+        }
+    }
+}
+
+impl<R> std::ops::Deref for ReceiverIterator<R> {
+    type Target = Bar;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pb
+    }
+}
+
+impl<R> std::ops::DerefMut for ReceiverIterator<R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.pb
+    }
+}
+
+impl<R: Channel> Iterator for ReceiverIterator<R> {
+    type Item = R::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(depth) = self.receiver.queue_depth() {
+            self.pb.set_postfix(format!("queue={}", depth));
+        }
+
+        if self.started {
+            self.pb.update(1);
+        } else {
+            self.pb.refresh();
+            self.started = true;
+        }
+
+        self.receiver.recv_item()
+    }
+}
+
+/// Channel receivers decoration with [ReceiverIterator](crate::ReceiverIterator).
+///
+/// Unlike iterators, channels don't know how many messages will be received,
+/// so total should be provided explicitly for producer/consumer pipelines.
+///
+/// # Example
+///
+/// ```
+/// use kdam::TqdmReceiver;
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// let (tx, rx) = mpsc::channel();
+///
+/// thread::spawn(move || {
+///     for i in 0..100 {
+///         tx.send(i).unwrap();
+///     }
+/// });
+///
+/// let sum = rx.tqdm_total(100).sum::<i32>();
+///
+/// eprint!("\n");
+/// assert_eq!(sum, 4950);
+/// ```
+pub trait TqdmReceiver
+where
+    Self: Sized + Channel,
+{
+    /// Decorate receiver to [ReceiverIterator](crate::ReceiverIterator) with unknown total.
+    fn tqdm(self) -> ReceiverIterator<Self>;
+
+    /// Decorate receiver to [ReceiverIterator](crate::ReceiverIterator) with expected number of messages.
+    fn tqdm_total(self, total: usize) -> ReceiverIterator<Self>;
+
+    /// Decorate receiver to [ReceiverIterator](crate::ReceiverIterator) with existing [Bar](crate::Bar).
+    fn tqdm_with_bar(self, pb: Bar) -> ReceiverIterator<Self>;
+}
+
+impl<R: Channel> TqdmReceiver for R {
+    fn tqdm(self) -> ReceiverIterator<Self> {
+        ReceiverIterator::new(self, Bar::default())
+    }
+
+    fn tqdm_total(self, total: usize) -> ReceiverIterator<Self> {
+        ReceiverIterator::new(self, Bar::new(total))
+    }
+
+    fn tqdm_with_bar(self, pb: Bar) -> ReceiverIterator<Self> {
+        ReceiverIterator::new(self, pb)
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// Source of monotonic time for [Bar](crate::Bar), which is used for elapsed time, rate, ETA and refresh intervals.
+///
+/// Default clock is [SystemClock](crate::SystemClock). Use [MockClock](crate::MockClock) in tests
+/// for asserting time dependent behaviour deterministically.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Returns time elapsed since an arbitrary but fixed origin.
+    fn now(&self) -> Duration;
+}
+
+/// Clock backed by system monotonic time. This is the default clock.
+#[derive(Debug, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        static ORIGIN: OnceLock<crate::Instant> = OnceLock::new();
+        ORIGIN.get_or_init(crate::Instant::now).elapsed()
+    }
+}
+
+/// Manually advanced clock for testing.
+/// Time is shared between clones of clock, so a clone can be given to bar while advancing the original one.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::CaptureRenderer, tqdm, BarExt, MockClock};
+/// use std::time::Duration;
+///
+/// let clock = MockClock::default();
+/// let capture = CaptureRenderer::default();
+/// let mut pb = tqdm!(total = 100, clock = clock.clone(), renderer = capture.clone());
+///
+/// clock.advance(Duration::from_secs(2));
+/// pb.update(50);
+///
+/// assert_eq!(pb.rate(), 25.0);
+/// assert_eq!(pb.remaining_time(), 2.0);
+/// assert!(capture.last().unwrap().ends_with("50/100 [00:02<00:02, 25.00it/s]"));
+///
+/// // refresh is skipped, since mininterval has not passed.
+/// assert!(!pb.update(1));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// Move clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+
+    /// Set current time of clock.
+    pub fn set(&self, now: Duration) {
+        *self.now.lock().unwrap() = now;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+}
@@ -0,0 +1,101 @@
+use crate::progress::{Bar, BarBuilder};
+use serde::{Deserialize, Serialize};
+
+/// Serializable configuration of [Bar](crate::Bar) styling and options,
+/// which can be loaded from a user config file (TOML/JSON etc.).
+/// Options which are `None` are left to their defaults.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{BarConfig, BarExt};
+///
+/// let config: BarConfig = serde_json::from_str(
+///     r#"{ "colour": "green", "animation": "ascii", "unit": "B", "unit_scale": true }"#,
+/// )
+/// .unwrap();
+///
+/// let mut pb = config.build(1024).unwrap();
+///
+/// for _ in 0..1024 {
+///     pb.update(1);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BarConfig {
+    /// Animation style, one of `arrow`, `ascii`, `blocks`, `classic`, `fillup`, `firacode` or `tqdm`.
+    pub animation: Option<String>,
+    /// Custom bar string formatting, see [BarBuilder::bar_format](crate::BarBuilder::bar_format).
+    /// It is only applied if `template` feature is enabled.
+    pub bar_format: Option<String>,
+    /// Bar colour (e.g. "green", "#00ff00").
+    pub colour: Option<String>,
+    /// Prefix for the progress bar.
+    pub desc: Option<String>,
+    /// If true, keeps all traces of the progress bar upon termination of iteration.
+    pub leave: Option<bool>,
+    /// Minimum progress display update interval (in seconds).
+    pub mininterval: Option<f32>,
+    /// The width of the entire output message.
+    pub ncols: Option<i16>,
+    /// String that will be used to define the unit of each iteration.
+    pub unit: Option<String>,
+    /// Divisor used when `unit_scale` is true.
+    pub unit_divisor: Option<usize>,
+    /// If true, the number of iterations will be reduced/scaled automatically.
+    pub unit_scale: Option<bool>,
+}
+
+impl BarConfig {
+    /// Apply configuration to [BarBuilder](crate::BarBuilder).
+    pub fn apply(&self, mut builder: BarBuilder) -> BarBuilder {
+        if let Some(animation) = &self.animation {
+            builder = builder.animation(animation.as_str());
+        }
+
+        #[cfg(feature = "template")]
+        if let Some(bar_format) = &self.bar_format {
+            builder = builder.bar_format(bar_format.as_str());
+        }
+
+        if let Some(colour) = &self.colour {
+            builder = builder.colour(colour.as_str());
+        }
+
+        if let Some(desc) = &self.desc {
+            builder = builder.desc(desc.as_str());
+        }
+
+        if let Some(leave) = self.leave {
+            builder = builder.leave(leave);
+        }
+
+        if let Some(mininterval) = self.mininterval {
+            builder = builder.mininterval(mininterval);
+        }
+
+        if let Some(ncols) = self.ncols {
+            builder = builder.ncols(ncols);
+        }
+
+        if let Some(unit) = &self.unit {
+            builder = builder.unit(unit.as_str());
+        }
+
+        if let Some(unit_divisor) = self.unit_divisor {
+            builder = builder.unit_divisor(unit_divisor);
+        }
+
+        if let Some(unit_scale) = self.unit_scale {
+            builder = builder.unit_scale(unit_scale);
+        }
+
+        builder
+    }
+
+    /// Build [Bar](crate::Bar) with a total value from configuration.
+    pub fn build(&self, total: usize) -> Result<Bar, crate::Error> {
+        self.apply(BarBuilder::default().total(total)).build()
+    }
+}
@@ -1,4 +1,6 @@
 /// Comman progress bar functionalities shared between different types of progress bars.
+///
+/// Wrapper types can use [impl_bar_ext](crate::impl_bar_ext) macro for forwarding all methods to a field.
 pub trait BarExt {
     /// Clear current bar display.
     fn clear(&mut self);
@@ -6,6 +8,35 @@ pub trait BarExt {
     /// Take input via bar (without overlap with bars).
     fn input<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error>;
 
+    /// Take input via bar like [input](Self::input), but input is not echoed back to terminal.
+    /// Useful for reading secrets such as passwords.
+    /// Echo can only be turned off on unix platforms, on other platforms an
+    /// [Unsupported](std::io::ErrorKind::Unsupported) error is returned instead of echoing input.
+    fn input_masked<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error>;
+
+    /// Take input via bar, redrawing bar above prompt while waiting for input.
+    /// Returns `None` if no input is given within `timeout`, in which case input typed later is left in stdin.
+    /// Only unix platforms are supported, on other platforms an [Unsupported](std::io::ErrorKind::Unsupported) error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use kdam::{tqdm, BarExt};
+    /// use std::time::Duration;
+    ///
+    /// let mut pb = tqdm!(total = 100);
+    ///
+    /// match pb.input_timeout("continue? (y/n) ", Duration::from_secs(10)).unwrap() {
+    ///     Some(answer) => println!("answer: {}", answer.trim()),
+    ///     None => println!("timed out"),
+    /// }
+    /// ```
+    fn input_timeout<T: Into<String>>(
+        &mut self,
+        text: T,
+        timeout: std::time::Duration,
+    ) -> Result<Option<String>, std::io::Error>;
+
     /// Force refresh the display of this bar.
     fn refresh(&mut self);
 
@@ -13,36 +44,333 @@ pub trait BarExt {
     fn render(&mut self) -> String;
 
     /// Resets to intial iterations for repeated use.
+    /// Counter, timer, postfix and other state of previous run (elapsed time, stall, failed items, recorded history etc.)
+    /// are cleared, while style of bar is kept. Use [Bar::reset_with](crate::Bar::reset_with) for replacing style too.
     /// Consider combining with `leave=true`.
     fn reset(&mut self, total: Option<usize>);
 
+    /// Clear bar, run `f` which may print arbitrary output or launch an external program (e.g. `$EDITOR`),
+    /// and then redraw bar. Returns output of `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 10);
+    ///
+    /// for i in 0..10 {
+    ///     pb.update(1);
+    ///     pb.suspend(|| println!("done {}", i));
+    /// }
+    ///
+    /// eprint!("\n");
+    /// ```
+    fn suspend<F: FnOnce() -> R, R>(&mut self, f: F) -> R {
+        self.clear();
+        let output = f();
+        self.refresh();
+        output
+    }
+
     /// Manually update the progress bar, useful for streams such as reading files.
-    fn update(&mut self, n: usize);
+    /// Returns wheter bar was rendered or not, which can be used to run expensive side work only on drawn frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100, force_refresh = true);
+    ///
+    /// for _ in 0..100 {
+    ///     if pb.update(1) {
+    ///         // flush metrics, update window title etc.
+    ///     }
+    /// }
+    ///
+    /// eprint!("\n");
+    /// ```
+    fn update(&mut self, n: usize) -> bool;
+
+    /// Same as `self.update` but returns io errors instead of panicking.
+    fn try_update(&mut self, n: usize) -> Result<bool, std::io::Error>;
 
     /// Manually update the progress bar to a writer, useful for streams such as reading files.
+    /// Returns wheter bar was rendered or not.
     #[cfg(feature = "writer")]
     #[cfg_attr(docsrs, doc(cfg(feature = "writer")))]
-    fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T);
+    fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T) -> bool;
 
     /// Set counter position instead of incrementing progress bar through `self.update`.
     /// Alternative way to update bar.
-    fn update_to(&mut self, update_to_n: usize);
+    /// Returns wheter bar was rendered or not.
+    fn update_to(&mut self, update_to_n: usize) -> bool;
 
     /// Set counter position instead of incrementing progress bar through `self.update_writer`.
     /// Alternative way to update bar.
+    /// Returns wheter bar was rendered or not.
     #[cfg(feature = "writer")]
     #[cfg_attr(docsrs, doc(cfg(feature = "writer")))]
-    fn update_to_writer<T: std::io::Write>(&mut self, update_to_n: usize, writer: &mut T);
+    fn update_to_writer<T: std::io::Write>(&mut self, update_to_n: usize, writer: &mut T) -> bool;
 
     /// Print a message via bar (without overlap with bars).
     fn write<T: Into<String>>(&mut self, text: T);
+
+    /// Print [Arguments](std::fmt::Arguments) via bar (without overlap with bars).
+    /// Unlike `self.write`, message is not collected into a `String` first.
+    /// This method also allows to use `write!` macro with bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 10);
+    ///
+    /// for i in 0..10 {
+    ///     pb.update(1);
+    ///     pb.write_fmt(format_args!("done {}", i));
+    ///     write!(pb, "done {}", i);
+    /// }
+    /// ```
+    fn write_fmt(&mut self, args: std::fmt::Arguments);
+
+    /// Print a message via bar (without overlap with bars) to specific writer, instead of bar's writer.
+    fn write_to<T: Into<String>>(&mut self, text: T, writer: crate::term::Writer);
+}
+
+/// Helper methods built on top of [BarExt](crate::BarExt) and underlying [Bar](crate::Bar).
+///
+/// This trait is implemented for every type which implements [BarExt](crate::BarExt) along with
+/// `AsRef<Bar>` and `AsMut<Bar>`, so implementors of [BarExt](crate::BarExt) don't need to provide anything else.
+pub trait BarHelpers: BarExt + AsRef<crate::Bar> + AsMut<crate::Bar> {
+    /// Complete progress, counter is set to total (if total is known) and bar is refreshed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, BarHelpers};
+    ///
+    /// let mut pb = tqdm!(total = 100);
+    /// pb.update(42);
+    /// pb.finish();
+    ///
+    /// assert_eq!(pb.fraction(), 1.0);
+    ///
+    /// eprint!("\n");
+    /// ```
+    fn finish(&mut self) {
+        let total = self.as_ref().get_total();
+
+        if total != 0 {
+            self.as_mut().set_counter(total);
+        }
+
+        self.refresh();
+    }
+
+    /// Returns progress as a fraction between 0 and 1, see [Bar::percentage](crate::Bar::percentage).
+    fn fraction(&self) -> f64 {
+        self.as_ref().clamped_percentage()
+    }
+
+    /// Set postfix from key value pairs i.e. `loss=0.42, epoch=3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, BarHelpers};
+    ///
+    /// let mut pb = tqdm!(total = 100);
+    /// pb.set_postfix_kv(&[("loss", &0.42), ("epoch", &3)]);
+    ///
+    /// assert!(pb.render().ends_with(", loss=0.42, epoch=3]"));
+    /// ```
+    fn set_postfix_kv(&mut self, kv: &[(&str, &dyn std::fmt::Display)]) {
+        let postfix = kv
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<String>>()
+            .join(", ");
+        self.as_mut().set_postfix(postfix);
+    }
 }
 
+impl<T: BarExt + AsRef<crate::Bar> + AsMut<crate::Bar> + ?Sized> BarHelpers for T {}
+
+/// Print a formatted message via bar (without overlap with bars), like `println!`.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{bar_println, tqdm, BarExt};
+///
+/// let mut pb = tqdm!(total = 10);
+///
+/// for i in 0..10 {
+///     pb.update(1);
+///     bar_println!(pb, "done {}", i);
+/// }
+/// ```
+#[macro_export]
+macro_rules! bar_println {
+    ($pb: expr, $($arg: tt)*) => {
+        $crate::BarExt::write_fmt(&mut $pb, format_args!($($arg)*))
+    };
+}
+
+/// Implement [BarExt](crate::BarExt) for a wrapper type by forwarding every method to its `field`,
+/// which is a [Bar](crate::Bar) or any other type implementing [BarExt](crate::BarExt).
+/// `AsRef<Bar>` and `AsMut<Bar>` are implemented too, so [BarHelpers](crate::BarHelpers) are available for wrapper type.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{impl_bar_ext, tqdm, Bar, BarExt, BarHelpers};
+///
+/// struct Job {
+///     id: usize,
+///     progress: Bar,
+/// }
+///
+/// impl_bar_ext!(Job, progress);
+///
+/// let mut job = Job {
+///     id: 1,
+///     progress: tqdm!(total = 10),
+/// };
+///
+/// let id = job.id;
+/// job.set_postfix_kv(&[("job", &id)]);
+/// job.update(5);
+/// assert_eq!(job.progress.get_counter(), 5);
+///
+/// eprint!("\n");
+/// ```
+#[macro_export]
+macro_rules! impl_bar_ext {
+    ($struct: ty, $field: ident) => {
+        impl AsRef<$crate::Bar> for $struct {
+            fn as_ref(&self) -> &$crate::Bar {
+                AsRef::<$crate::Bar>::as_ref(&self.$field)
+            }
+        }
+
+        impl AsMut<$crate::Bar> for $struct {
+            fn as_mut(&mut self) -> &mut $crate::Bar {
+                AsMut::<$crate::Bar>::as_mut(&mut self.$field)
+            }
+        }
+
+        impl $crate::BarExt for $struct {
+            fn clear(&mut self) {
+                $crate::BarExt::clear(&mut self.$field)
+            }
+
+            fn input<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error> {
+                $crate::BarExt::input(&mut self.$field, text)
+            }
+
+            fn input_masked<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error> {
+                $crate::BarExt::input_masked(&mut self.$field, text)
+            }
+
+            fn input_timeout<T: Into<String>>(
+                &mut self,
+                text: T,
+                timeout: std::time::Duration,
+            ) -> Result<Option<String>, std::io::Error> {
+                $crate::BarExt::input_timeout(&mut self.$field, text, timeout)
+            }
+
+            fn refresh(&mut self) {
+                $crate::BarExt::refresh(&mut self.$field)
+            }
+
+            fn render(&mut self) -> String {
+                $crate::BarExt::render(&mut self.$field)
+            }
+
+            fn reset(&mut self, total: Option<usize>) {
+                $crate::BarExt::reset(&mut self.$field, total)
+            }
+
+            fn suspend<F: FnOnce() -> R, R>(&mut self, f: F) -> R {
+                $crate::BarExt::suspend(&mut self.$field, f)
+            }
+
+            fn try_update(&mut self, n: usize) -> Result<bool, std::io::Error> {
+                $crate::BarExt::try_update(&mut self.$field, n)
+            }
+
+            fn update(&mut self, n: usize) -> bool {
+                $crate::BarExt::update(&mut self.$field, n)
+            }
+
+            fn update_to(&mut self, update_to_n: usize) -> bool {
+                $crate::BarExt::update_to(&mut self.$field, update_to_n)
+            }
+
+            $crate::_impl_bar_ext_writer!($field);
+
+            fn write<T: Into<String>>(&mut self, text: T) {
+                $crate::BarExt::write(&mut self.$field, text)
+            }
+
+            fn write_fmt(&mut self, args: std::fmt::Arguments) {
+                $crate::BarExt::write_fmt(&mut self.$field, args)
+            }
+
+            fn write_to<T: Into<String>>(&mut self, text: T, writer: $crate::term::Writer) {
+                $crate::BarExt::write_to(&mut self.$field, text, writer)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "writer")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _impl_bar_ext_writer {
+    ($field: ident) => {
+        fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T) -> bool {
+            $crate::BarExt::update_writer(&mut self.$field, n, writer)
+        }
+
+        fn update_to_writer<T: std::io::Write>(
+            &mut self,
+            update_to_n: usize,
+            writer: &mut T,
+        ) -> bool {
+            $crate::BarExt::update_to_writer(&mut self.$field, update_to_n, writer)
+        }
+    };
+}
+
+#[cfg(not(feature = "writer"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _impl_bar_ext_writer {
+    ($field: ident) => {};
+}
 
 #[macro_export]
 #[doc(hidden)]
 macro_rules! _impl_bar_methods {
     ($struct: ident, $render: ident) => {
+        impl AsRef<$crate::Bar> for $struct {
+            fn as_ref(&self) -> &$crate::Bar {
+                &self.pb
+            }
+        }
+
+        impl AsMut<$crate::Bar> for $struct {
+            fn as_mut(&mut self) -> &mut $crate::Bar {
+                &mut self.pb
+            }
+        }
+
         impl $crate::BarExt for $struct {
             fn clear(&mut self) {
                 self.pb.clear();
@@ -62,6 +390,47 @@ macro_rules! _impl_bar_methods {
                 Ok(input_string)
             }
 
+            fn input_masked<T: Into<String>>(&mut self, text: T) -> Result<String, std::io::Error> {
+                self.clear();
+                self.pb.get_writer().print_str(&text.into());
+
+                let input_string = $crate::term::read_line_masked()?;
+
+                if self.pb.get_leave() {
+                    self.refresh();
+                }
+
+                Ok(input_string)
+            }
+
+            fn input_timeout<T: Into<String>>(
+                &mut self,
+                text: T,
+                timeout: std::time::Duration,
+            ) -> Result<Option<String>, std::io::Error> {
+                self.clear();
+                let writer = self.pb.get_writer();
+                let bar = self.render();
+                writer.print(format_args!("\r{}\n{}", bar, text.into()));
+
+                let interval =
+                    std::time::Duration::from_secs_f32(self.pb.get_mininterval().max(0.1));
+                let input_string = $crate::term::read_line_timeout(timeout, interval, || {
+                    let bar = self.render();
+                    writer.print(format_args!("\x1b7\x1b[1A\r{}\x1b8", bar));
+                })?;
+
+                if input_string.is_none() {
+                    writer.print_str("\n");
+                }
+
+                if self.pb.get_leave() {
+                    self.refresh();
+                }
+
+                Ok(input_string)
+            }
+
             fn refresh(&mut self) {
                 if !self.pb.get_force_refresh() {
                     self.pb.set_force_refresh(true);
@@ -80,25 +449,47 @@ macro_rules! _impl_bar_methods {
                 self.pb.reset(total);
             }
 
-            fn update(&mut self, n: usize) {
+            fn suspend<F: FnOnce() -> R, R>(&mut self, f: F) -> R {
+                self.pb.clear();
+                self.pb.get_writer().print_str("\r");
+                let output = f();
+                self.refresh();
+                output
+            }
+
+            fn try_update(&mut self, n: usize) -> Result<bool, std::io::Error> {
                 if self.pb.trigger(n) {
+                    let started = $crate::Instant::now();
                     let text = self.render();
-                    let length = $crate::term::Colorizer::len_ansi(text.as_str()) as i16;
+                    let length = $crate::term::Colorizer::len_ansi(
+                        text.split('\n').next().unwrap_or_default(),
+                    ) as i16;
 
                     if length != self.pb.get_bar_length() {
-                        self.pb.clear();
+                        self.pb.try_clear()?;
                     }
 
                     self.pb.set_bar_length(length);
-                    self.pb.write_at(text);
+                    self.pb.try_write_at(&text)?;
+                    self.pb.recycle_frame(text);
+                    self.pb.record_render_cost(started);
+                    return Ok(true);
                 }
+
+                Ok(false)
+            }
+
+            fn update(&mut self, n: usize) -> bool {
+                self.try_update(n).unwrap()
             }
 
             #[cfg(feature = "writer")]
-            fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T) {
+            fn update_writer<T: std::io::Write>(&mut self, n: usize, writer: &mut T) -> bool {
                 if self.pb.trigger(n) {
                     let text = self.render();
-                    let length = $crate::term::Colorizer::len_ansi(text.as_str()) as i16;
+                    let length = $crate::term::Colorizer::len_ansi(
+                        text.split('\n').next().unwrap_or_default(),
+                    ) as i16;
 
                     if length != self.pb.get_bar_length() {
                         self.pb.clear();
@@ -106,29 +497,49 @@ macro_rules! _impl_bar_methods {
 
                     self.pb.set_bar_length(length);
 
-                    crate::thread::lock::acquire();
-                    writer.write_fmt(format_args!("{}\n", text.as_str())).unwrap();
+                    $crate::thread::lock::acquire();
+                    writer
+                        .write_fmt(format_args!("{}\n", text.as_str()))
+                        .unwrap();
                     writer.flush().unwrap();
-                    crate::thread::lock::release();
+                    $crate::thread::lock::release();
+                    return true;
                 }
+
+                false
             }
 
-            fn update_to(&mut self, update_to_n: usize) {
+            fn update_to(&mut self, update_to_n: usize) -> bool {
                 self.pb.set_counter(update_to_n);
-                self.update(0);
+                self.update(0)
             }
 
             #[cfg(feature = "writer")]
-            fn update_to_writer<T: std::io::Write>(&mut self, update_to_n: usize, writer: &mut T) {
+            fn update_to_writer<T: std::io::Write>(
+                &mut self,
+                update_to_n: usize,
+                writer: &mut T,
+            ) -> bool {
                 self.pb.set_counter(update_to_n);
-                self.update_writer(0, writer);
+                self.update_writer(0, writer)
             }
 
             fn write<T: Into<String>>(&mut self, text: T) {
+                self.write_fmt(format_args!("{}", text.into()));
+            }
+
+            fn write_fmt(&mut self, args: std::fmt::Arguments) {
+                self.pb.clear();
+                self.pb.get_writer().print(format_args!("\r{}\n", args));
+
+                if self.pb.get_leave() {
+                    self.refresh();
+                }
+            }
+
+            fn write_to<T: Into<String>>(&mut self, text: T, writer: $crate::term::Writer) {
                 self.pb.clear();
-                self.pb
-                    .get_writer()
-                    .print(format_args!("\r{}\n", text.into()));
+                writer.print(format_args!("\r{}\n", text.into()));
 
                 if self.pb.get_leave() {
                     self.refresh();
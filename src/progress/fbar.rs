@@ -0,0 +1,94 @@
+use crate::progress::{Bar, BarExt};
+
+/// Progress bar with floating-point counter and total, useful for scientific workloads
+/// like simulation time steps or integrating over a fractional range.
+///
+/// Internally counter is stored as fixed point number in a [Bar](crate::Bar),
+/// so rendering, refresh logic and every other feature of [Bar](crate::Bar) are same.
+/// Counter and total are displayed with `decimals` fractional digits.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, FBar};
+///
+/// let mut pb = FBar::new(tqdm!(unit = "s"), 10.0, 2);
+///
+/// while pb.get_counter() < 10.0 {
+///     pb.update(0.25);
+/// }
+///
+/// assert_eq!(pb.get_counter(), 10.0);
+/// assert_eq!(pb.pb.get_counter(), 1000);
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug)]
+pub struct FBar {
+    /// Instance of [Bar](crate::Bar) which is used for rendering.
+    pub pb: Bar,
+    counter: f64,
+    total: f64,
+}
+
+impl FBar {
+    /// Create a new instance of [FBar](crate::FBar) from a bar with a total value
+    /// and number of fractional digits to track and display.
+    /// You can also set `total=0.0` if total is unknown.
+    pub fn new(mut pb: Bar, total: f64, decimals: usize) -> Self {
+        pb.decimals = Some(decimals);
+        let counter = pb.get_counter() as f64 / 10_f64.powi(decimals as i32);
+        let mut fpb = Self {
+            pb,
+            counter,
+            total: 0.0,
+        };
+        fpb.set_total(total);
+        fpb
+    }
+
+    /// Returns floating-point counter.
+    pub fn get_counter(&self) -> f64 {
+        self.counter
+    }
+
+    /// Returns floating-point total.
+    pub fn get_total(&self) -> f64 {
+        self.total
+    }
+
+    /// Set floating-point total.
+    pub fn set_total(&mut self, total: f64) {
+        self.total = total.max(0.0);
+        self.pb.set_total(self.scaled(self.total));
+    }
+
+    /// Returns progress rate in floating-point units per second.
+    pub fn rate(&self) -> f64 {
+        self.counter / self.pb.elapsed_time as f64
+    }
+
+    /// Increment floating-point counter by `n` and display updated bar if needed.
+    /// Returns wheter bar was refreshed or not.
+    pub fn update(&mut self, n: f64) -> bool {
+        self.update_to(self.counter + n)
+    }
+
+    /// Set floating-point counter to `n` and display updated bar if needed.
+    /// Returns wheter bar was refreshed or not.
+    pub fn update_to(&mut self, n: f64) -> bool {
+        self.counter = n.max(0.0);
+        self.pb.update_to(self.scaled(self.counter))
+    }
+
+    /// Force refresh the display of this bar.
+    pub fn refresh(&mut self) {
+        self.pb.refresh();
+    }
+
+    /// Convert floating-point value into fixed point counter units of bar.
+    /// Values are rounded, so repeated small updates don't drift.
+    fn scaled(&self, value: f64) -> usize {
+        (value * 10_f64.powi(self.pb.decimals.unwrap_or(0) as i32)).round() as usize
+    }
+}
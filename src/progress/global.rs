@@ -0,0 +1,121 @@
+//! Process wide settings shared by all progress bars.
+
+use crate::styles::Animation;
+use crate::term::Writer;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+const UNINIT: u8 = 0;
+const ENABLED: u8 = 1;
+const DISABLED: u8 = 2;
+
+static STATE: AtomicU8 = AtomicU8::new(UNINIT);
+static DEFAULTS: RwLock<Option<Defaults>> = RwLock::new(None);
+
+/// Enable or disable all progress bars of current process.
+///
+/// This overrides `KDAM_DISABLE` environment variable.
+/// Disabled bars behave exactly like bars created with `disable=true`.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+///
+/// kdam::set_enabled(false);
+///
+/// let mut pb = tqdm!(total = 100);
+/// assert!(!pb.update(100));
+///
+/// kdam::set_enabled(true);
+/// ```
+pub fn set_enabled(enabled: bool) {
+    STATE.store(if enabled { ENABLED } else { DISABLED }, Ordering::SeqCst);
+}
+
+/// Returns wheter progress bars are enabled or not.
+///
+/// If `set_enabled` was never called then bars are disabled
+/// only when `KDAM_DISABLE` environment variable is set to `1` or `true`.
+pub fn is_enabled() -> bool {
+    match STATE.load(Ordering::Acquire) {
+        ENABLED => true,
+        DISABLED => false,
+        _ => {
+            let enabled = !matches!(
+                std::env::var("KDAM_DISABLE")
+                    .unwrap_or_default()
+                    .to_lowercase()
+                    .as_str(),
+                "1" | "true"
+            );
+            let _ = STATE.compare_exchange(
+                UNINIT,
+                if enabled { ENABLED } else { DISABLED },
+                Ordering::SeqCst,
+                Ordering::Acquire,
+            );
+            enabled
+        }
+    }
+}
+
+/// Default options applied to every [Bar](crate::Bar) created after [set_defaults](crate::set_defaults) is called.
+/// Options which are `None` are left unchanged, and options given to [tqdm!](crate::tqdm!) or [BarBuilder](crate::BarBuilder) take precedence.
+#[derive(Debug, Clone, Default)]
+pub struct Defaults {
+    /// Animation style of bars.
+    pub animation: Option<Animation>,
+    /// Colour of bars.
+    pub colour: Option<String>,
+    /// Minimum progress display update interval (in seconds).
+    pub mininterval: Option<f32>,
+    /// Output target of bars.
+    pub writer: Option<Writer>,
+}
+
+/// Set process wide default options of progress bars, so that appearance of bars can be standardized at one place.
+/// Passing `Defaults::default()` restores built-in defaults.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, Animation, Defaults};
+///
+/// kdam::set_defaults(Defaults {
+///     animation: Some(Animation::FillUp),
+///     colour: Some("green".to_owned()),
+///     ..Default::default()
+/// })
+/// .unwrap();
+///
+/// let pb = tqdm!(total = 100);
+/// let pb = tqdm!(total = 100, colour = "red");
+///
+/// kdam::set_defaults(Defaults::default()).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns error if colour or animation charset is invalid.
+pub fn set_defaults(defaults: Defaults) -> Result<(), crate::Error> {
+    if let Some(colour) = &defaults.colour {
+        crate::term::Colour::parse(colour)?;
+    }
+
+    if let Some(animation) = &defaults.animation {
+        animation.validate()?;
+    }
+
+    *DEFAULTS.write().unwrap_or_else(|x| x.into_inner()) = Some(defaults);
+    Ok(())
+}
+
+/// Returns process wide default options, see [set_defaults](crate::set_defaults).
+pub fn get_defaults() -> Defaults {
+    DEFAULTS
+        .read()
+        .unwrap_or_else(|x| x.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
@@ -1,13 +1,32 @@
 use super::{Bar, BarExt};
 
 /// Iterable version of [Bar](crate::Bar).
+///
+/// `ExactSizeIterator`, `DoubleEndedIterator` and `FusedIterator` implementations of iterable are preserved,
+/// so adapters like `.rev()` and `.len()` keep working.
+///
+/// # Example
+///
+/// ```
+/// use kdam::TqdmIterator;
+///
+/// let mut iter = (0..4).tqdm().rev();
+/// assert_eq!(iter.len(), 4);
+/// assert_eq!(iter.next(), Some(3));
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![2, 1, 0]);
+///
+/// eprint!("\n");
+/// ```
 #[derive(Debug)]
 pub struct BarIterator<T> {
     /// Iterator to decorate with a progress bar.
     pub iterable: T,
     /// Instance of [Bar](crate::Bar) to display progress updates for iterable.
     pub pb: Bar,
+    estimated: bool,
     started: bool,
+    throttle: Option<(std::time::Duration, crate::Instant)>,
 }
 
 impl<T: Iterator> BarIterator<T> {
@@ -19,7 +38,9 @@ impl<T: Iterator> BarIterator<T> {
         BarIterator {
             iterable,
             pb,
+            estimated: false,
             started: false,
+            throttle: None,
         }
     }
 
@@ -30,7 +51,9 @@ impl<T: Iterator> BarIterator<T> {
         let mut pb_iter = BarIterator {
             iterable,
             pb,
+            estimated: false,
             started: false,
+            throttle: None,
         };
 
         if pb_iter.pb.indefinite() {
@@ -39,6 +62,92 @@ impl<T: Iterator> BarIterator<T> {
 
         pb_iter
     }
+
+    /// Estimate total using upper bound of `size_hint`, which is refined on every iteration as iterable shrinks.
+    /// This keeps percentage meaningful for adapters like `filter`, whose lower bound is always 0.
+    /// Total is left unchanged if upper bound is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::TqdmIterator;
+    ///
+    /// let mut iter = (0..100).filter(|x| x % 2 == 0).tqdm().with_estimated_total();
+    /// assert_eq!(iter.pb.get_total(), 100);
+    ///
+    /// assert_eq!(iter.by_ref().count(), 50);
+    /// assert_eq!(iter.pb.get_total(), 50);
+    ///
+    /// eprint!("\n");
+    /// ```
+    pub fn with_estimated_total(mut self) -> Self {
+        self.estimated = true;
+        self.refine_total(false);
+        self
+    }
+
+    /// Limit iteration rate to at most `rate` items per second, sleeping before yielding items as needed.
+    /// Useful for API clients with rate limits. Non positive rates are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::TqdmIterator;
+    ///
+    /// let started = std::time::Instant::now();
+    ///
+    /// for _ in (0..5).tqdm().throttle(50.0) {}
+    ///
+    /// assert!(started.elapsed().as_secs_f32() >= 0.08);
+    /// eprint!("\n");
+    /// ```
+    pub fn throttle(mut self, rate: f32) -> Self {
+        self.throttle = if rate > 0.0 {
+            // Rates too low to be represented are clamped to longest interval.
+            let interval = std::time::Duration::try_from_secs_f32(1.0 / rate)
+                .unwrap_or(std::time::Duration::MAX);
+            Some((interval, crate::Instant::now()))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Sleep until next item is allowed by throttle, `item` is only yielded after waiting if iterable isn't exhausted.
+    fn wait<S>(&mut self, item: Option<S>) -> Option<S> {
+        if let (Some((interval, next_allowed)), Some(_)) = (&mut self.throttle, &item) {
+            let now = crate::Instant::now();
+
+            if now < *next_allowed {
+                std::thread::sleep(*next_allowed - now);
+            }
+
+            let start = (*next_allowed).max(now);
+            *next_allowed = start.checked_add(*interval).unwrap_or(start);
+        }
+
+        item
+    }
+
+    /// Set total to number of completed iterations plus upper bound of remaining iterations,
+    /// `fetched` is wheter an item was just taken from iterable but not yet counted.
+    fn refine_total(&mut self, fetched: bool) {
+        if let (_, Some(upper)) = self.iterable.size_hint() {
+            let total =
+                self.pb.get_counter() + usize::from(self.started) + usize::from(fetched) + upper;
+            self.pb.set_total(total);
+        }
+    }
+
+    /// Count previously yielded item, or display bar if iteration is just started.
+    fn tick(&mut self) {
+        if self.started {
+            self.pb.update(1);
+        } else {
+            self.pb.refresh();
+            self.started = true;
+        }
+    }
 }
 
 impl<T> std::ops::Deref for BarIterator<T> {
@@ -65,30 +174,40 @@ impl<S, T: Iterator<Item = S>> Iterator for BarIterator<T> {
     type Item = S;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.started {
-            self.pb.update(1);
-        } else {
-            self.pb.refresh();
-            self.started = true;
+        if self.estimated {
+            let item = self.iterable.next();
+            self.refine_total(item.is_some());
+            self.tick();
+            return self.wait(item);
         }
 
-        self.iterable.next()
+        self.tick();
+        let item = self.iterable.next();
+        self.wait(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterable.size_hint()
     }
 }
 
 impl<T: DoubleEndedIterator> DoubleEndedIterator for BarIterator<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.started {
-            self.pb.update(1);
-        } else {
-            self.pb.refresh();
-            self.started = true;
+        if self.estimated {
+            let item = self.iterable.next_back();
+            self.refine_total(item.is_some());
+            self.tick();
+            return self.wait(item);
         }
 
-        self.iterable.next_back()
+        self.tick();
+        let item = self.iterable.next_back();
+        self.wait(item)
     }
 }
 
+impl<T: std::iter::FusedIterator> std::iter::FusedIterator for BarIterator<T> {}
+
 /// Rust iterators decoration with [BarIterator](crate::BarIterator).
 pub trait TqdmIterator
 where
@@ -113,6 +232,10 @@ where
     /// ```
     fn tqdm(self) -> BarIterator<Self>;
 
+    /// Decorate any iterator to [BarIterator](crate::BarIterator) with an explicit total,
+    /// useful when `size_hint` is useless e.g. `receiver.iter().tqdm_total(n)`.
+    fn tqdm_total(self, total: usize) -> BarIterator<Self>;
+
     /// Decorate any sized iterator to [BarIterator](crate::BarIterator) with existing [Bar](crate::Bar).
     fn tqdm_with_bar(self, pb: Bar) -> BarIterator<Self>;
 }
@@ -122,6 +245,10 @@ impl<S, T: Iterator<Item = S>> TqdmIterator for T {
         BarIterator::new(self)
     }
 
+    fn tqdm_total(self, total: usize) -> BarIterator<Self> {
+        BarIterator::new_with_bar(self, Bar::new(total))
+    }
+
     fn tqdm_with_bar(self, pb: Bar) -> BarIterator<Self> {
         BarIterator::new_with_bar(self, pb)
     }
@@ -0,0 +1,157 @@
+use crate::progress::{Clock, SystemClock};
+use std::time::Duration;
+
+/// Accumulates per-iteration durations over a sliding window and computes latency percentiles.
+///
+/// It can be used standalone for building postfix, or it can be attached to a [Bar](crate::Bar)
+/// using [Bar::with_latency](crate::Bar::with_latency), in which case time between updates is recorded automatically
+/// and `p50`, `p95`, `p99` template placeholders become available.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, Latency};
+/// use std::time::Duration;
+///
+/// let mut pb = tqdm!(total = 100);
+/// let mut latency = Latency::default();
+///
+/// for i in 0..100 {
+///     latency.record(Duration::from_millis(i));
+///     pb.set_postfix(latency.postfix());
+///     pb.update(1);
+/// }
+///
+/// assert_eq!(latency.postfix(), "p50=49.0ms p95=94.0ms p99=98.0ms");
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Latency {
+    capacity: usize,
+    last_tick: Option<Duration>,
+    next: usize,
+    samples: Vec<f32>,
+}
+
+impl Default for Latency {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl Latency {
+    /// Create a new instance of [Latency](crate::Latency) which keeps last `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            last_tick: None,
+            next: 0,
+            samples: vec![],
+        }
+    }
+
+    /// Returns maximum number of samples kept in window.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Record duration of a single iteration.
+    pub fn record(&mut self, duration: Duration) {
+        self.push(duration.as_secs_f32());
+    }
+
+    /// Record time elapsed since previous tick, divided equally between `n` iterations.
+    /// First tick only starts the timer.
+    pub fn tick(&mut self, n: usize) {
+        self.tick_at(n, SystemClock.now());
+    }
+
+    /// Same as [tick](Self::tick), but time is read from a [Clock](crate::Clock) by caller (e.g. clock of a bar).
+    pub(crate) fn tick_at(&mut self, n: usize, now: Duration) {
+        if let Some(last_tick) = self.last_tick {
+            if n == 0 {
+                return;
+            }
+
+            let duration = now.saturating_sub(last_tick).as_secs_f32() / n as f32;
+
+            for _ in 0..n.min(self.capacity) {
+                self.push(duration);
+            }
+        }
+
+        self.last_tick = Some(now);
+    }
+
+    /// Remove all recorded samples and reset timer of [tick](Self::tick).
+    pub fn clear(&mut self) {
+        self.last_tick = None;
+        self.next = 0;
+        self.samples.clear();
+    }
+
+    /// Returns number of recorded samples in window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns wheter any sample is recorded or not.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns percentile of recorded samples using nearest-rank method, `fraction` must be between 0 and 1.
+    /// Returns `None` if no sample is recorded.
+    pub fn percentile(&self, fraction: f64) -> Option<Duration> {
+        Self::nearest_rank(&self.sorted(), fraction).map(|x| Duration::from_secs_f32(x.max(0.0)))
+    }
+
+    /// Returns `p50`, `p95` and `p99` formatted as postfix, like `p50=12.0ms p95=30.1ms p99=41.5ms`.
+    pub fn postfix(&self) -> String {
+        let sorted = self.sorted();
+
+        format!(
+            "p50={} p95={} p99={}",
+            Self::fmt_percentile(&sorted, 0.5),
+            Self::fmt_percentile(&sorted, 0.95),
+            Self::fmt_percentile(&sorted, 0.99)
+        )
+    }
+
+    /// Returns recorded samples in ascending order, so that multiple percentiles can be computed with a single sort.
+    pub(crate) fn sorted(&self) -> Vec<f32> {
+        let mut samples = self.samples.clone();
+        samples.sort_by(|a, b| a.total_cmp(b));
+        samples
+    }
+
+    /// Returns formatted percentile of `sorted` samples, like `12.0ms` or `?` if no sample is recorded.
+    pub(crate) fn fmt_percentile(sorted: &[f32], fraction: f64) -> String {
+        match Self::nearest_rank(sorted, fraction) {
+            Some(secs) if secs < 1e-3 => format!("{:.0}µs", secs * 1e6),
+            Some(secs) if secs < 1.0 => format!("{:.1}ms", secs * 1e3),
+            Some(secs) => format!("{:.2}s", secs),
+            None => "?".to_owned(),
+        }
+    }
+
+    fn nearest_rank(sorted: &[f32], fraction: f64) -> Option<f32> {
+        if sorted.is_empty() {
+            return None;
+        }
+
+        let rank = (fraction.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.saturating_sub(1)])
+    }
+
+    fn push(&mut self, secs: f32) {
+        if self.samples.len() < self.capacity {
+            self.samples.push(secs);
+        } else {
+            self.samples[self.next] = secs;
+        }
+
+        self.next = (self.next + 1) % self.capacity;
+    }
+}
@@ -1,9 +1,30 @@
 mod bar;
+mod channel;
+mod clock;
+#[cfg(feature = "serde")]
+mod config;
 mod extensions;
+mod fbar;
+mod global;
 mod iterator;
+mod latency;
+mod notify;
 mod rich;
+mod secondary;
+mod timeline;
 
-pub use bar::{Bar, BarBuilder};
-pub use extensions::BarExt;
+pub use bar::{Bar, BarBuilder, BarState, Eta, LayoutPriority, OnComplete, Overflow};
+pub use channel::{Channel, ReceiverIterator, TqdmReceiver};
+pub use clock::{Clock, MockClock, SystemClock};
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use config::BarConfig;
+pub use extensions::{BarExt, BarHelpers};
+pub use fbar::FBar;
+pub use global::{get_defaults, is_enabled, set_defaults, set_enabled, Defaults};
 pub use iterator::{BarIterator, TqdmIterator};
+pub use latency::Latency;
+pub use notify::Notify;
 pub use rich::{Column, RichProgress};
+pub use secondary::Secondary;
+pub use timeline::Timeline;
@@ -0,0 +1,60 @@
+use crate::term::Writer;
+
+/// Notification which is sent once when progress is completed, see [Bar::notify_on_complete](crate::Bar::notify_on_complete).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Notify {
+    /// Ring terminal bell (`\x07`), most terminals flash or highlight their tab.
+    TerminalBell,
+    /// Show a desktop notification using `notify-send` (linux), `osascript` (macos) or `powershell` (windows).
+    /// Notification is sent in background and failures are ignored.
+    #[cfg(feature = "notify")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "notify")))]
+    Desktop,
+}
+
+impl Notify {
+    /// Send notification with description of completed progress bar.
+    #[cfg_attr(not(feature = "notify"), allow(unused_variables))]
+    pub(crate) fn send(&self, writer: &Writer, desc: &str) {
+        match self {
+            Self::TerminalBell => writer.print_str("\x07"),
+            #[cfg(feature = "notify")]
+            Self::Desktop => desktop(if desc.is_empty() { "completed" } else { desc }),
+        }
+    }
+}
+
+#[cfg(feature = "notify")]
+fn desktop(body: &str) {
+    let mut command;
+
+    if cfg!(target_os = "macos") {
+        command = std::process::Command::new("osascript");
+        command.arg("-e").arg(format!(
+            "display notification \"{}\" with title \"kdam\"",
+            body.replace(['"', '\\'], "")
+        ));
+    } else if cfg!(windows) {
+        command = std::process::Command::new("powershell");
+        command.arg("-NoProfile").arg("-Command").arg(format!(
+            "[reflection.assembly]::loadwithpartialname('System.Windows.Forms') | Out-Null; \
+             $n = New-Object System.Windows.Forms.NotifyIcon; \
+             $n.Icon = [System.Drawing.SystemIcons]::Information; $n.Visible = $true; \
+             $n.ShowBalloonTip(5000, 'kdam', '{}', 'Info'); Start-Sleep -Seconds 5; $n.Dispose()",
+            body.replace('\'', "''")
+        ));
+    } else {
+        command = std::process::Command::new("notify-send");
+        command.arg("kdam").arg(body);
+    }
+
+    let child = command
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn();
+
+    if let Ok(mut child) = child {
+        std::thread::spawn(move || child.wait());
+    }
+}
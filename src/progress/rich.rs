@@ -1,4 +1,4 @@
-use crate::progress::Bar;
+use crate::progress::{Bar, BarExt};
 use crate::term::Colorizer;
 
 /// Renderable columns for [RichProgress](crate::RichProgress).
@@ -10,18 +10,55 @@ pub enum Column {
     /// If progress.pb.n || progress.pb.total == 0, then an pulsating animation
     /// else rich style animation.
     Bar,
+    /// Single cell indicator which fills up vertically as progress grows, like a battery.
+    /// Useful when horizontal space is extremely constrained.
+    /// If progress is indefinite, then cell pulsates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Column, RichProgress};
+    ///
+    /// let pb = RichProgress::new(
+    ///     tqdm!(total = 100),
+    ///     vec![Column::Battery, Column::Percentage(0)]
+    /// );
+    /// ```
+    Battery,
     /// Progress counter i.e. `sel.pb.n`.
     Count,
     /// Formatted counter i.e. `progress.pb.n / progress.pb.total`
     CountTotal,
+    /// CPU usage of current process in percentage.
+    #[cfg(feature = "sysinfo")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sysinfo")))]
+    Cpu,
     /// Progress elapsed time
     ElapsedTime,
+    /// Resident memory of current process, like `24.6MB`.
+    #[cfg(feature = "sysinfo")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sysinfo")))]
+    Memory,
     /// Progress percentage done, with precision.
     Percentage(usize),
     /// Progress update rate.
     Rate,
     /// Progress remaining time / ETA.
     RemainingTime,
+    /// Sparkline of recent rate history, with number of samples to display.
+    /// Requires history recording, see [Bar::with_history](crate::Bar::with_history).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, Column, RichProgress};
+    ///
+    /// let pb = RichProgress::new(
+    ///     tqdm!(total = 100).with_history(),
+    ///     vec![Column::Bar, Column::Rate, Column::Sparkline(10)]
+    /// );
+    /// ```
+    Sparkline(usize),
     /// Spinner for progress. See more styles at [rich repository](https://github.com/Textualize/rich/blob/master/rich/_spinners.py).
     /// - first argument is Vec<String> of frames.
     /// - second argument is interval of frames.
@@ -43,6 +80,7 @@ pub enum Column {
     /// ````
     Spinner(Vec<String>, f32, f32),
     /// Text column.
+    /// Columns after a newline are displayed in [extra_lines](crate::BarBuilder::extra_lines) of bar.
     ///
     /// # Example`
     ///
@@ -101,12 +139,86 @@ pub struct RichProgress {
     pub pb: Bar,
     /// Vector of renderable columns.
     pub columns: Vec<Column>,
+    transient: bool,
 }
 
 impl RichProgress {
     /// Create a new instance of [RichProgress](crate::RichProgress).
     pub fn new(pb: Bar, columns: Vec<Column>) -> Self {
-        Self { pb, columns }
+        Self {
+            pb,
+            columns,
+            transient: false,
+        }
+    }
+
+    /// Remove entire display once progress is completed, like transient progress of rich.
+    /// This leaves terminal clean for final summary output, regardless of `leave` value of inner bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, Column, RichProgress};
+    ///
+    /// let mut pb = RichProgress::new(
+    ///     tqdm!(total = 100),
+    ///     vec![Column::Bar, Column::Percentage(2)]
+    /// )
+    /// .with_transient();
+    ///
+    /// for _ in 0..100 {
+    ///     pb.update(1);
+    /// }
+    ///
+    /// eprintln!("done");
+    /// ```
+    pub fn with_transient(mut self) -> Self {
+        self.transient = true;
+        self
+    }
+
+    /// Returns wheter display is removed once progress is completed or not.
+    pub fn is_transient(&self) -> bool {
+        self.transient
+    }
+
+    /// Print a message above progress display, like `rich.console.print`.
+    /// Display is cleared, message is printed and then display is repainted with same columns layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, Column, RichProgress};
+    ///
+    /// let mut pb = RichProgress::new(
+    ///     tqdm!(total = 100),
+    ///     vec![Column::Bar, Column::Percentage(2)]
+    /// );
+    ///
+    /// for i in 0..100 {
+    ///     if i % 50 == 0 {
+    ///         pb.print(format!("reached {}", i));
+    ///     }
+    ///
+    ///     pb.update(1);
+    /// }
+    ///
+    /// pb.log("done");
+    /// eprint!("\n");
+    /// ```
+    pub fn print<T: std::fmt::Display>(&mut self, text: T) {
+        self.pb.clear();
+        self.pb.get_writer().print(format_args!("\r{}\n", text));
+
+        if !(self.transient && self.pb.completed()) {
+            self.refresh();
+        }
+    }
+
+    /// Print a message above progress display prefixed with elapsed time, like `rich.console.log`.
+    pub fn log<T: std::fmt::Display>(&mut self, text: T) {
+        let elapsed_time = format!("[{}]", self.pb.fmt_elapsed_time()).colorize("cyan");
+        self.print(format_args!("{} {}", elapsed_time, text));
     }
 
     /// Replace a column value at specific index.
@@ -119,10 +231,19 @@ impl RichProgress {
 crate::_impl_bar_methods!(RichProgress, render);
 
 fn render(progress: &mut RichProgress) -> String {
+    // Every row of display is cleared by renderer, including extra lines of `\n` in text columns.
+    if progress.transient && progress.pb.completed() {
+        progress.pb.clear();
+        return String::new();
+    }
+
     let mut bar_text = vec![];
     let mut bar_length = 0;
     let mut progress_bar_index = None;
+    let mut first_row_length = None;
     let et = progress.pb.elapsed_time();
+    progress.pb.check_stall();
+    progress.pb.check_notify();
 
     for col in progress.columns.clone() {
         match col {
@@ -131,47 +252,83 @@ fn render(progress: &mut RichProgress) -> String {
                 bar_text.push(String::new());
             }
 
+            Column::Battery => {
+                let eighths = if progress.pb.indefinite() {
+                    (et * 8.0) as usize % 8 + 1
+                } else {
+                    (progress.pb.clamped_percentage() * 8.0).round() as usize
+                };
+                bar_length += 1;
+                bar_text.push(crate::styles::fillup(eighths).colorize("magenta"));
+            }
+
             Column::Count => {
                 let fmt_progress = progress.pb.fmt_counter();
-                bar_length += fmt_progress.chars().count();
+                bar_length += fmt_progress.len_ansi();
                 bar_text.push(fmt_progress.colorize("green"));
             }
 
             Column::CountTotal => {
                 let fmt_progress =
                     format!("{}/{}", progress.pb.fmt_counter(), progress.pb.fmt_total());
-                bar_length += fmt_progress.chars().count();
+                bar_length += fmt_progress.len_ansi();
                 bar_text.push(fmt_progress.colorize("green"));
             }
 
+            #[cfg(feature = "sysinfo")]
+            Column::Cpu => {
+                let cpu = format!("{:.1}%", crate::term::process_usage().0);
+                bar_length += cpu.len_ansi();
+                bar_text.push(cpu.colorize("yellow"));
+            }
+
             Column::ElapsedTime => {
                 let elapsed_time = progress.pb.fmt_elapsed_time();
-                bar_length += elapsed_time.chars().count();
+                bar_length += elapsed_time.len_ansi();
                 bar_text.push(elapsed_time.colorize("cyan"));
             }
 
+            #[cfg(feature = "sysinfo")]
+            Column::Memory => {
+                let memory =
+                    crate::format::format_sizeof(crate::term::process_usage().1 as f64, 1024.)
+                        + "B";
+                bar_length += memory.len_ansi();
+                bar_text.push(memory.colorize("yellow"));
+            }
+
             Column::Percentage(precision) => {
-                let percentage = format!("{:.1$}%", progress.pb.percentage() * 100., precision);
-                bar_length += percentage.chars().count();
+                let percentage = format!(
+                    "{:.1$}%",
+                    progress.pb.clamped_percentage() * 100.,
+                    precision
+                );
+                bar_length += percentage.len_ansi();
                 bar_text.push(percentage.colorize("magenta"));
             }
 
             Column::Rate => {
                 let speed = progress.pb.fmt_rate();
-                bar_length += speed.chars().count();
+                bar_length += speed.len_ansi();
                 bar_text.push(speed.colorize("red"));
             }
 
             Column::RemainingTime => {
                 let remaining_time = progress.pb.fmt_remaining_time();
-                bar_length += remaining_time.chars().count();
+                bar_length += remaining_time.len_ansi();
                 bar_text.push(remaining_time.colorize("cyan"));
             }
 
+            Column::Sparkline(width) => {
+                let sparkline = progress.pb.fmt_sparkline(width);
+                bar_length += width;
+                bar_text.push(sparkline.colorize("red"));
+            }
+
             Column::Spinner(frames, interval, speed) => {
                 let frame_no = (progress.pb.elapsed_time() * speed) / (interval / 1000.0);
                 let frame = frames.get(frame_no as usize % frames.len()).unwrap();
-                bar_length += frame.chars().count();
+                bar_length += frame.len_ansi();
                 bar_text.push(frame.colorize("green"));
             }
 
@@ -187,6 +344,8 @@ fn render(progress: &mut RichProgress) -> String {
                     _ => None,
                 };
 
+                let columns_length = bar_length + bar_text.len();
+
                 if let Some(code) = color {
                     let text = text.replace(&format!("[{}]", code), "");
                     bar_length += text.len_ansi();
@@ -195,17 +354,31 @@ fn render(progress: &mut RichProgress) -> String {
                     bar_length += text.len_ansi();
                     bar_text.push(text);
                 }
+
+                if let Some((head, _)) = bar_text.last().unwrap().split_once('\n') {
+                    first_row_length.get_or_insert(columns_length + head.len_ansi());
+                }
             }
 
             Column::Total => {
                 let fmt_progress = progress.pb.fmt_total();
-                bar_length += fmt_progress.chars().count();
+                bar_length += fmt_progress.len_ansi();
                 bar_text.push(fmt_progress.colorize("green"));
             }
         }
     }
 
+    if progress.pb.is_stalled() {
+        let stall = progress.pb.fmt_stall();
+        bar_length += stall.len_ansi();
+        bar_text.push(stall.trim_start().colorize("yellow"));
+    }
+
     bar_length += bar_text.len() - 1;
+
+    if let Some(first_row_length) = first_row_length {
+        bar_length = first_row_length;
+    }
     let mut ncols = 0;
 
     if let Some(progress_bar_index) = progress_bar_index {
@@ -219,13 +392,20 @@ fn render(progress: &mut RichProgress) -> String {
                 if progress.pb.indefinite() || !progress.pb.started() {
                     crate::styles::rich::pulse(ncols, et)
                 } else {
-                    crate::styles::rich::bar(progress.pb.percentage() as f32, ncols)
+                    crate::styles::rich::bar(
+                        progress.pb.clamped_percentage() as f32,
+                        ncols,
+                        progress.pb.get_accessible(),
+                    )
                 };
         }
     }
 
-    progress
-        .pb
-        .set_bar_length(bar_length as i16 + ncols);
-    bar_text.join(" ")
+    progress.pb.set_bar_length(bar_length as i16 + ncols);
+
+    if first_row_length.is_some() {
+        bar_text.join(" ").replace(" \n ", "\n")
+    } else {
+        bar_text.join(" ")
+    }
 }
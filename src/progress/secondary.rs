@@ -0,0 +1,122 @@
+use crate::format;
+use std::borrow::Cow;
+
+/// Secondary counter which is tracked and displayed alongside counter of [Bar](crate::Bar), i.e. bytes along with records.
+///
+/// Secondary counter has its own unit and scaling options. It can be attached to a [Bar](crate::Bar)
+/// using [Bar::with_secondary](crate::Bar::with_secondary), in which case it is displayed after counter of bar
+/// i.e. `1.20k rec, 58.0MB [00:19, 3.05MB/s]` and rate of bar is displayed using secondary counter.
+/// `secondary` and `secondary_rate` template placeholders also become available.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt, Secondary};
+///
+/// let mut pb = tqdm!(unit = "rec", unit_scale = true)
+///     .with_secondary(Secondary::new("B").unit_scale(true).unit_divisor(1024));
+///
+/// for record in ["id,name", "1,kdam", "2,tqdm"] {
+///     pb.update_secondary(1, record.len());
+/// }
+///
+/// assert_eq!(pb.get_secondary().unwrap().get_counter(), 19);
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Secondary {
+    counter: usize,
+    unit: Cow<'static, str>,
+    unit_divisor: usize,
+    unit_scale: bool,
+}
+
+impl Secondary {
+    /// Create a new secondary counter with `unit`.
+    pub fn new<T: Into<String>>(unit: T) -> Self {
+        Self {
+            counter: 0,
+            unit: Cow::Owned(unit.into()),
+            unit_divisor: 1000,
+            unit_scale: false,
+        }
+    }
+
+    /// If true, the secondary counter will be reduced/scaled automatically and a metric prefix following the
+    /// International System of Units standard will be added (kilo, mega, etc.).
+    /// (default: `false`)
+    pub fn unit_scale(mut self, unit_scale: bool) -> Self {
+        self.unit_scale = unit_scale;
+        self
+    }
+
+    /// Divisor used when `unit_scale` is true.
+    /// (default: `1000`)
+    pub fn unit_divisor(mut self, unit_divisor: usize) -> Self {
+        self.unit_divisor = unit_divisor;
+        self
+    }
+
+    /// Returns secondary counter value.
+    pub fn get_counter(&self) -> usize {
+        self.counter
+    }
+
+    /// Returns unit of secondary counter.
+    pub fn get_unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns a copy of secondary counter with same options and counter set to 0.
+    pub(crate) fn fork(&self) -> Self {
+        Self {
+            counter: 0,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn add(&mut self, n: usize) {
+        self.counter += n;
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.counter = 0;
+    }
+
+    /// Returns secondary counter followed by its unit i.e. `58.0MB`.
+    pub(crate) fn fmt_counter(&self, sizeof_options: &format::SizeofOptions) -> String {
+        format!(
+            "{}{}",
+            self.fmt_number(self.counter as f64, sizeof_options),
+            self.unit
+        )
+    }
+
+    /// Returns rate of secondary counter over `elapsed_time` i.e. `3.05MB/s`.
+    pub(crate) fn fmt_rate(
+        &self,
+        elapsed_time: f32,
+        sizeof_options: &format::SizeofOptions,
+    ) -> String {
+        if self.counter == 0 {
+            return format!("?{}/s", self.unit);
+        }
+
+        let rate = self.counter as f64 / elapsed_time as f64;
+
+        if self.unit_scale {
+            format!("{}{}/s", self.fmt_number(rate, sizeof_options), self.unit)
+        } else {
+            format!("{:.2}{}/s", rate, self.unit)
+        }
+    }
+
+    fn fmt_number(&self, num: f64, sizeof_options: &format::SizeofOptions) -> String {
+        if self.unit_scale {
+            format::format_sizeof_with(num, self.unit_divisor as f64, sizeof_options)
+        } else {
+            format!("{}", num)
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+
+/// Bounded ring buffer of timestamped `(elapsed time, counter, rate)` samples of a [Bar](crate::Bar).
+///
+/// It is attached to a bar using [Bar::with_history](crate::Bar::with_history) or
+/// [Bar::with_history_capacity](crate::Bar::with_history_capacity), in which case
+/// samples are recorded automatically on updates, at most once per `mininterval`.
+/// Only last `capacity` samples are kept, so memory usage is bounded for long running jobs.
+/// Recorded samples can be exported for post-hoc analysis of throughput using
+/// [export::timeline_csv](crate::export::timeline_csv) and [export::timeline_json](crate::export::timeline_json).
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+///
+/// let mut pb = tqdm!(total = 100, mininterval = 0.0).with_history_capacity(10);
+///
+/// for _ in 0..100 {
+///     pb.update(1);
+/// }
+///
+/// let timeline = pb.get_history().unwrap();
+/// assert!(timeline.len() <= 10);
+/// assert_eq!(timeline.iter().last().unwrap().1, 100);
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    capacity: usize,
+    samples: VecDeque<(f32, usize, f32)>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl Timeline {
+    /// Create a new instance of [Timeline](crate::Timeline) which keeps last `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Returns maximum number of samples kept in buffer.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns number of recorded samples in buffer.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns wheter any sample is recorded or not.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns iterator over recorded `(elapsed time, counter, rate)` samples, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &(f32, usize, f32)> {
+        self.samples.iter()
+    }
+
+    /// Returns most recently recorded sample.
+    pub fn last(&self) -> Option<&(f32, usize, f32)> {
+        self.samples.back()
+    }
+
+    /// Remove all recorded samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    pub(crate) fn push(&mut self, elapsed_time: f32, counter: usize, rate: f32) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back((elapsed_time, counter, rate));
+    }
+}
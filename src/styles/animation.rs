@@ -1,14 +1,33 @@
 use crate::term::Colorizer;
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub(crate) const FILLUPCHARSET: [&str; 8] = [
+    "\u{2581}", "\u{2582}", "\u{2583}", "\u{2584}", "\u{2585}", "\u{2586}", "\u{2587}", "\u{2588}",
+];
+pub(crate) const TQDMCHARSET: [&str; 8] = [
+    "\u{258F}", "\u{258E}", "\u{258D}", "\u{258C}", "\u{258B}", "\u{258A}", "\u{2589}", "\u{2588}",
+];
+pub(crate) const TQDMASCIICHARSET: [&str; 10] = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "#"];
 
 /// Bar animation styles for [Bar](crate::Bar).
 #[derive(Debug, Clone)]
 pub enum Animation {
     Arrow,
+    Blocks,
     Classic,
     Custom(Vec<String>),
+    CustomSpinnerBar(Box<Animation>, Vec<String>, f32),
     CustomWithFill(Vec<String>, String),
     FillUp,
     FiraCode,
+    Plain,
     Tqdm,
     TqdmAscii,
 }
@@ -17,9 +36,11 @@ impl From<&str> for Animation {
     fn from(animation: &str) -> Self {
         match animation.to_lowercase().as_str() {
             "arrow" => Self::Arrow,
+            "blocks" => Self::Blocks,
             "classic" => Self::Classic,
             "fillup" => Self::FillUp,
             "firacode" => Self::FiraCode,
+            "plain" => Self::Plain,
             "ascii" => Self::TqdmAscii,
             _ => Self::Tqdm,
         }
@@ -29,12 +50,18 @@ impl From<&str> for Animation {
 impl Animation {
     /// Construct [Animation::Custom](crate::Animation) enum variant.
     ///
+    /// Glyphs can be multi codepoint strings such as emoji or combining sequences.
+    /// Wide glyphs occupy multiple columns, so bar is rendered using `ncols / width` glyphs.
+    ///
     /// # Example
     ///
     /// ```
     /// use kdam::Animation;
     ///
     /// let anim = Animation::custom(&["\\", "|", "/", "-"]);
+    ///
+    /// let emoji = Animation::custom(&["⬜", "🟨", "🟩"]);
+    /// assert_eq!(emoji.progress(0.5, 9), "🟩🟩🟨   ");
     /// ```
     pub fn custom(charset: &[&str]) -> Self {
         Self::Custom(
@@ -64,112 +91,400 @@ impl Animation {
         )
     }
 
+    /// Construct [Animation::CustomSpinnerBar](crate::Animation) enum variant,
+    /// which renders a spinner frame before bar `animation`.
+    /// Spinner frames are advanced by elapsed time, one frame per `interval` (in milliseconds).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Animation;
+    ///
+    /// let anim = Animation::custom_spinner_bar(Animation::Tqdm, &["⠋", "⠙", "⠹", "⠸"], 80.0);
+    /// assert_eq!(anim.fmt_spinner(0.1), "⠙ ");
+    /// assert_eq!(anim.progress(0.5, 4), "██▎ ");
+    /// ```
+    pub fn custom_spinner_bar(animation: Animation, frames: &[&str], interval: f32) -> Self {
+        Self::CustomSpinnerBar(
+            Box::new(animation),
+            frames
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>(),
+            interval,
+        )
+    }
+
+    /// Check that custom charset, fill and spinner frames of animation can be rendered.
+    ///
+    /// A charset must have at least two glyphs and all glyphs must have same non zero display width,
+    /// otherwise fractional blocks can't be aligned. Fill must have same display width as glyphs.
+    /// This is also checked by [BarBuilder::build](crate::BarBuilder::build).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{Animation, Error};
+    ///
+    /// assert!(Animation::custom(&["🌑", "🌓", "🌕"]).validate().is_ok());
+    /// assert!(matches!(
+    ///     Animation::custom(&["#"]).validate(),
+    ///     Err(Error::InvalidCharset(_))
+    /// ));
+    /// assert!(matches!(
+    ///     Animation::custom(&[".", "🌕"]).validate(),
+    ///     Err(Error::InvalidCharset(_))
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        let invalid = |message: String| Err(crate::Error::InvalidCharset(message));
+
+        let (charset, fill) = match self {
+            Self::Custom(charset) => (charset, None),
+            Self::CustomWithFill(charset, fill) => (charset, Some(fill)),
+            Self::CustomSpinnerBar(animation, frames, interval) => {
+                if interval.is_nan() || *interval <= 0.0 {
+                    return invalid(format!(
+                        "spinner interval must be positive, found {}",
+                        interval
+                    ));
+                }
+
+                if let Some(frame) = frames
+                    .iter()
+                    .find(|x| glyph_width(x) != glyph_width(&frames[0]))
+                {
+                    return invalid(format!(
+                        "spinner frames {:?} and {:?} have different display widths",
+                        frames[0], frame
+                    ));
+                }
+
+                return animation.validate();
+            }
+            _ => return Ok(()),
+        };
+
+        if charset.len() < 2 {
+            return invalid(format!(
+                "at least 2 glyphs are required, found {}",
+                charset.len()
+            ));
+        }
+
+        let width = glyph_width(&charset[0]);
+
+        if width == 0 {
+            return invalid(format!("glyph {:?} has zero display width", charset[0]));
+        }
+
+        if let Some(glyph) = charset.iter().find(|x| glyph_width(x) != width) {
+            return invalid(format!(
+                "glyphs {:?} and {:?} have different display widths",
+                charset[0], glyph
+            ));
+        }
+
+        if let Some(fill) = fill {
+            if glyph_width(fill) != width {
+                return invalid(format!(
+                    "fill {:?} and glyph {:?} have different display widths",
+                    fill, charset[0]
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns colour blind safe version of animation,
+    /// in which remaining part of bar is filled with a visible pattern instead of blank spaces.
+    /// Animations which already use distinct glyphs for remaining part are returned as it is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Animation;
+    ///
+    /// assert_eq!(Animation::Tqdm.accessible().progress(0.5, 4), "██▎·");
+    /// ```
+    pub fn accessible(&self) -> Self {
+        let to_vec = |charset: &[&str]| charset.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+
+        match self {
+            Self::Custom(charset) => {
+                let width = charset.last().map_or(1, |x| glyph_width(x).max(1));
+                Self::CustomWithFill(charset.clone(), "\u{00B7}".repeat(width))
+            }
+            Self::CustomSpinnerBar(animation, frames, interval) => {
+                Self::CustomSpinnerBar(Box::new(animation.accessible()), frames.clone(), *interval)
+            }
+            Self::Blocks | Self::FillUp => {
+                Self::CustomWithFill(to_vec(&FILLUPCHARSET), "\u{00B7}".to_owned())
+            }
+            Self::Tqdm => Self::CustomWithFill(to_vec(&TQDMCHARSET), "\u{00B7}".to_owned()),
+            Self::TqdmAscii => Self::CustomWithFill(to_vec(&TQDMASCIICHARSET), ".".to_owned()),
+            _ => self.clone(),
+        }
+    }
+
     /// Generate progress bar animation.
     ///
     /// # Arguments
     ///
     /// - progress: It can be from range (0.0 - 1.0) inclusive.
     /// - ncols: number of columns to render.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Animation;
+    ///
+    /// assert_eq!(Animation::Blocks.progress(0.5, 4), "██  ");
+    /// assert_eq!(Animation::Blocks.progress(0.28125, 4), "█▁  ");
+    /// assert_eq!(Animation::Custom(vec!["#".to_owned()]).progress(0.6, 4), "##  ");
+    /// assert_eq!(Animation::Plain.progress(0.5, 8), "####>---");
+    /// ```
     pub fn progress(&self, progress: f32, ncols: i16) -> String {
+        let mut bar_animation = String::new();
+        self.write_progress(&mut bar_animation, progress, ncols);
+        bar_animation
+    }
+
+    /// Write progress bar animation to end of `buf`, see [progress](Self::progress).
+    pub(crate) fn write_progress(&self, buf: &mut String, progress: f32, ncols: i16) {
         match self {
             Self::Arrow => {
                 let block = (ncols as f32 * progress) as i16;
-                format!(
-                    "{}{}",
-                    "=".repeat(block as usize),
-                    if progress >= 1.0 {
-                        "".to_owned()
-                    } else {
-                        ">".to_owned() + &" ".repeat((ncols - block - 1) as usize)
-                    }
-                )
+                push_repeat(buf, "=", block as usize);
+
+                if progress < 1.0 {
+                    buf.push('>');
+                    push_repeat(buf, " ", (ncols - block - 1) as usize);
+                }
+            }
+
+            Self::Blocks => {
+                let (bar_length, frac_bar_length) = crate::styles::format::divmod(
+                    (progress.clamp(0.0, 1.0) * ncols as f32 * 8.0 + 0.5) as usize,
+                    8,
+                );
+                push_repeat(buf, FILLUPCHARSET[7], bar_length);
+
+                if bar_length < ncols as usize {
+                    buf.push_str(fillup(frac_bar_length));
+                    push_repeat(buf, " ", ncols as usize - bar_length - 1);
+                }
             }
 
             Self::Classic => {
                 let block = (ncols as f32 * progress) as i16;
-                format!(
-                    "{}{}",
-                    "#".repeat(block as usize),
-                    if progress >= 1.0 {
-                        "".to_owned()
-                    } else {
-                        "#".to_owned() + &".".repeat((ncols - block - 1) as usize)
-                    }
-                )
+                push_repeat(buf, "#", block as usize);
+
+                if progress < 1.0 {
+                    buf.push('#');
+                    push_repeat(buf, ".", (ncols - block - 1) as usize);
+                }
+            }
+
+            Self::CustomSpinnerBar(animation, _, _) => {
+                animation.write_progress(buf, progress, ncols)
+            }
+
+            Self::Plain => {
+                let block = (ncols as f32 * progress) as i16;
+                push_repeat(buf, "#", block as usize);
+
+                if progress < 1.0 {
+                    buf.push('>');
+                    push_repeat(buf, "-", (ncols - block - 1) as usize);
+                }
             }
 
             Self::FiraCode => {
                 let block = (ncols as f32 * progress) as i16;
-                format!(
-                    "\u{EE03}{}{}{}",
-                    "\u{EE04}".repeat(block as usize),
-                    "\u{EE01}".repeat((ncols - block) as usize),
-                    if progress >= 1.0 {
-                        "\u{EE05}"
-                    } else {
-                        "\u{EE02}"
-                    }
-                )
+                buf.push('\u{EE03}');
+                push_repeat(buf, "\u{EE04}", block as usize);
+                push_repeat(buf, "\u{EE01}", (ncols - block) as usize);
+                buf.push(if progress >= 1.0 {
+                    '\u{EE05}'
+                } else {
+                    '\u{EE02}'
+                });
             }
 
-            _ => {
-                let mut fill = None;
-
-                let charset = match self {
-                    Self::TqdmAscii => vec!["1", "2", "3", "4", "5", "6", "7", "8", "9", "#"],
-                    Self::FillUp => vec![
-                        "\u{2581}", "\u{2582}", "\u{2583}", "\u{2584}", "\u{2585}", "\u{2586}",
-                        "\u{2587}", "\u{2588}",
-                    ],
-                    Self::Custom(custom_charset) => {
-                        custom_charset.iter().map(|x| x.as_str()).collect::<_>()
-                    }
-                    Self::CustomWithFill(custom_charset, filling) => {
-                        fill = Some(filling.to_owned());
-                        custom_charset.iter().map(|x| x.as_str()).collect::<_>()
-                    }
-                    _ => vec![
-                        "\u{258F}", "\u{258E}", "\u{258D}", "\u{258C}", "\u{258B}", "\u{258A}",
-                        "\u{2589}", "\u{2588}",
-                    ],
-                };
-
-                let nsyms = charset.len() - 1;
-                let (bar_length, frac_bar_length) = crate::styles::format::divmod(
-                    (progress * ncols as f32 * nsyms as f32) as usize,
-                    nsyms,
-                );
-                let mut bar_animation = charset.last().unwrap().repeat(bar_length);
+            Self::TqdmAscii => write_charset(buf, &TQDMASCIICHARSET, None, progress, ncols),
+            Self::FillUp => write_charset(buf, &FILLUPCHARSET, None, progress, ncols),
+            Self::Custom(charset) => write_charset(buf, charset, None, progress, ncols),
+            Self::CustomWithFill(charset, fill) => {
+                write_charset(buf, charset, Some(fill), progress, ncols)
+            }
+            Self::Tqdm => write_charset(buf, &TQDMCHARSET, None, progress, ncols),
+        }
+    }
 
-                if bar_length < ncols as usize {
-                    bar_animation += charset[frac_bar_length + 1];
-
-                    if let Some(filling) = fill {
-                        bar_animation +=
-                            &filling.repeat((ncols - (bar_length as i16) - 1) as usize);
-                    } else {
-                        bar_animation += &" ".repeat((ncols - (bar_length as i16) - 1) as usize);
-                    }
-                }
+    /// Generate bouncing block animation, useful when progress is indefinite (total=0).
+    ///
+    /// # Arguments
+    ///
+    /// - elapsed_time: elapsed time which decides position of block.
+    /// - ncols: number of columns to render.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Animation;
+    ///
+    /// assert_eq!(Animation::Classic.bounce(0.0, 8), "##      ");
+    /// ```
+    pub fn bounce(&self, elapsed_time: f32, ncols: i16) -> String {
+        if ncols <= 0 {
+            return "".to_owned();
+        }
 
-                bar_animation
+        let (block, fill) = match self {
+            Self::CustomSpinnerBar(animation, _, _) => {
+                return animation.bounce(elapsed_time, ncols)
             }
+            Self::Arrow => ("=", " "),
+            Self::Classic | Self::TqdmAscii => ("#", " "),
+            Self::Custom(charset) => (charset.last().map(|x| x.as_str()).unwrap_or("#"), " "),
+            Self::CustomWithFill(charset, fill) => (
+                charset.last().map(|x| x.as_str()).unwrap_or("#"),
+                fill.as_str(),
+            ),
+            Self::FiraCode => ("\u{EE04}", "\u{EE01}"),
+            Self::Plain => ("#", "-"),
+            Self::Blocks | Self::FillUp | Self::Tqdm => ("\u{2588}", " "),
+        };
+
+        let width = glyph_width(block).max(1);
+        let nglyphs = (ncols as usize / width).max(1) as i16;
+        let fill_repeat = |n: usize| fill.repeat(n * width / glyph_width(fill).max(1));
+        let block_len = (nglyphs / 4).max(1);
+        let distance = (nglyphs - block_len) as usize;
+        let mut offset = 0;
+
+        if distance != 0 {
+            let step = (elapsed_time * nglyphs as f32) as usize % (2 * distance);
+            offset = if step > distance {
+                2 * distance - step
+            } else {
+                step
+            };
+        }
+
+        let bounce = fill_repeat(offset)
+            + &block.repeat(block_len as usize)
+            + &fill_repeat(distance - offset)
+            + &" ".repeat((ncols as usize).saturating_sub(nglyphs as usize * width));
+
+        if let Self::FiraCode = self {
+            format!("\u{EE00}{}\u{EE02}", bounce)
+        } else {
+            bounce
         }
     }
 
+    /// Formatted version of `self.bounce` with opening and closing brackets.
+    pub fn fmt_bounce(&self, elapsed_time: f32, ncols: i16, colour: &str) -> String {
+        self.fmt_animation(self.bounce(elapsed_time, ncols), colour)
+    }
+
     /// Formatted version of `self.progress` with opening and closing brackets.
     pub fn fmt_progress(&self, progress: f32, ncols: i16, colour: &str) -> String {
-        let (bar_open, bar_close) = match self {
-            Self::Arrow | Self::Classic => ("[", "]"),
-            Self::Custom(_)
-            | Self::CustomWithFill(_, _)
-            | Self::FillUp
-            | Self::Tqdm
-            | Self::TqdmAscii => ("|", "|"),
-            Self::FiraCode => (" ", ""),
+        self.fmt_animation(self.progress(progress, ncols), colour)
+    }
+
+    /// Write formatted progress bar animation to end of `buf`, see [fmt_progress](Self::fmt_progress).
+    /// Unlike `fmt_progress`, no intermediate strings are allocated for solid colours.
+    #[cfg(feature = "std")]
+    pub(crate) fn write_fmt_progress(
+        &self,
+        buf: &mut String,
+        progress: f32,
+        ncols: i16,
+        colour: &str,
+    ) {
+        if colour
+            .get(..9)
+            .is_some_and(|x| x.eq_ignore_ascii_case("gradient("))
+        {
+            buf.push_str(&self.fmt_progress(progress, ncols, colour));
+            return;
+        }
+
+        let (bar_open, bar_close) = self.brackets();
+        let esc_code = if colour == "default" {
+            "".to_owned()
+        } else {
+            crate::term::colour(colour)
         };
 
+        buf.push_str(bar_open);
+        buf.push_str(&esc_code);
+        self.write_progress(buf, progress, ncols);
+
+        if !esc_code.is_empty() {
+            buf.push_str("\x1b[0m");
+        }
+
+        buf.push_str(bar_close);
+    }
+
+    /// Formatted version of `self.progress` with two coloured segments,
+    /// columns upto `split` fraction of bar are coloured with first colour and rest with second colour.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Animation;
+    ///
+    /// let bar = Animation::Classic.fmt_progress_split(1.0, 0.5, 4, ("green", "red"));
+    /// assert_eq!(bar, "[\x1b[32m##\x1b[0m\x1b[31m##\x1b[0m]");
+    ///
+    /// let bar = Animation::custom(&["⬜", "🟩"]).fmt_progress_split(1.0, 0.5, 4, ("green", "red"));
+    /// assert_eq!(bar, "|\x1b[32m🟩\x1b[0m\x1b[31m🟩\x1b[0m|");
+    /// ```
+    pub fn fmt_progress_split(
+        &self,
+        progress: f32,
+        split: f32,
+        ncols: i16,
+        colours: (&str, &str),
+    ) -> String {
         let progress = self.progress(progress, ncols);
+        let index = (split.clamp(0.0, 1.0) * ncols as f32) as usize;
+        let mut columns = 0;
+        let at = progress
+            .grapheme_indices(true)
+            .find(|(_, glyph)| {
+                columns += glyph_width(glyph);
+                columns > index
+            })
+            .map_or(progress.len(), |(i, _)| i);
+        let (first, second) = progress.split_at(at);
+
+        self.fmt_animation(
+            first.colorize(colours.0) + &second.colorize(colours.1),
+            "default",
+        )
+    }
+
+    /// Returns spinner frame followed by a space at `elapsed_time`,
+    /// if animation is [Animation::CustomSpinnerBar](crate::Animation) else an empty string.
+    pub fn fmt_spinner(&self, elapsed_time: f32) -> String {
+        match self {
+            Self::CustomSpinnerBar(_, frames, interval) if !frames.is_empty() => {
+                let frame_no = elapsed_time / (interval / 1000.0);
+                frames[frame_no as usize % frames.len()].to_owned() + " "
+            }
+            _ => "".to_owned(),
+        }
+    }
+
+    fn fmt_animation(&self, progress: String, colour: &str) -> String {
+        let (bar_open, bar_close) = self.brackets();
 
         format!(
             "{}{}{}",
@@ -199,11 +514,90 @@ impl Animation {
         )
     }
 
+    /// Returns opening and closing brackets of bar.
+    fn brackets(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::CustomSpinnerBar(animation, _, _) => animation.brackets(),
+            Self::Arrow | Self::Classic | Self::Plain => ("[", "]"),
+            Self::Blocks
+            | Self::Custom(_)
+            | Self::CustomWithFill(_, _)
+            | Self::FillUp
+            | Self::Tqdm
+            | Self::TqdmAscii => ("|", "|"),
+            Self::FiraCode => (" ", ""),
+        }
+    }
+
     /// Returns extra spaces consumed by `self.fmt_progress`.
     pub fn spaces(&self) -> u8 {
         match self {
+            Self::CustomSpinnerBar(animation, frames, _) => {
+                animation.spaces() + frames.first().map_or(0, |x| glyph_width(x) as u8 + 1)
+            }
             Self::FiraCode => 3,
             _ => 2,
         }
     }
 }
+
+/// Write `text` repeated `n` times to end of `buf`, without allocating a temporary string.
+fn push_repeat(buf: &mut String, text: &str, n: usize) {
+    buf.reserve(text.len() * n);
+
+    for _ in 0..n {
+        buf.push_str(text);
+    }
+}
+
+/// Write fractional block animation using `charset`, remaining part of bar is filled with `fill` or spaces.
+/// Wide glyphs occupy multiple columns, so bar is rendered using `ncols / width` glyphs and padded with spaces.
+fn write_charset<T: AsRef<str>>(
+    buf: &mut String,
+    charset: &[T],
+    fill: Option<&str>,
+    progress: f32,
+    ncols: i16,
+) {
+    let full = charset.last().map_or("#", |x| x.as_ref());
+    let nsyms = charset.len().saturating_sub(1).max(1);
+    let width = glyph_width(full).max(1);
+    let nglyphs = ncols.max(0) as usize / width;
+    let (bar_length, frac_bar_length) = crate::styles::format::divmod(
+        (progress.clamp(0.0, 1.0) * nglyphs as f32 * nsyms as f32) as usize,
+        nsyms,
+    );
+    let bar_length = bar_length.min(nglyphs);
+    push_repeat(buf, full, bar_length);
+
+    if bar_length < nglyphs {
+        // Charsets with a single glyph have no partial glyphs, so partially filled cell is left blank.
+        match charset.get(frac_bar_length + 1) {
+            Some(glyph) => buf.push_str(glyph.as_ref()),
+            None => match fill {
+                Some(fill) => buf.push_str(fill),
+                None => push_repeat(buf, " ", width),
+            },
+        }
+
+        match fill {
+            Some(fill) => push_repeat(buf, fill, nglyphs - bar_length - 1),
+            None => push_repeat(buf, " ", (nglyphs - bar_length - 1) * width),
+        }
+    }
+
+    push_repeat(buf, " ", ncols.max(0) as usize - nglyphs * width);
+}
+
+/// Returns block glyph which is filled upto `eighths` of a cell, from blank to full block.
+pub(crate) fn fillup(eighths: usize) -> &'static str {
+    match eighths {
+        0 => " ",
+        x => FILLUPCHARSET[x.min(8) - 1],
+    }
+}
+
+/// Returns display width of a glyph, ignoring ANSI escape codes.
+pub(crate) fn glyph_width(glyph: &str) -> usize {
+    glyph.len_ansi()
+}
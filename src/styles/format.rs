@@ -1,27 +1,204 @@
 //! Functions for formatting values.
 
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Side from which text is truncated by [truncate](crate::format::truncate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Truncate {
+    /// Remove text from start i.e. `…world`.
+    Left,
+    /// Remove text from middle i.e. `he…ld`.
+    Middle,
+    /// Remove text from end i.e. `hell…`.
+    Right,
+    /// Scroll text horizontally by one grapheme per rendered frame, see [marquee](crate::format::marquee).
+    /// Stateless [truncate](crate::format::truncate) function treats it like `Right`.
+    Marquee,
+}
+
 /// Returns floor division and modulus of two values.
 pub fn divmod(x: usize, y: usize) -> (usize, usize) {
-    (x / y as usize, x % y)
+    (x / y, x % y)
+}
+
+/// Formats a number with thousands separators.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::format_grouped(1234567, ","), "1,234,567");
+/// assert_eq!(format::format_grouped(1234567, "."), "1.234.567");
+/// assert_eq!(format::format_grouped(123, ","), "123");
+/// ```
+pub fn format_grouped(num: usize, separator: &str) -> String {
+    let digits = num.to_string();
+    let mut grouped = String::with_capacity(digits.len() + (digits.len() / 3) * separator.len());
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped += separator;
+        }
+
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+/// Formats values as a sparkline using block characters (`▁▂▃▄▅▆▇█`).
+/// Values are scaled between minimum and maximum of given values.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::sparkline(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]), "▁▂▃▄▅▆▇█");
+/// assert_eq!(format::sparkline(&[5.0, 5.0]), "▄▄");
+/// ```
+pub fn sparkline(values: &[f64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    values
+        .iter()
+        .map(|x| {
+            if max - min <= f64::EPSILON {
+                BLOCKS[3]
+            } else {
+                BLOCKS[(((x - min) / (max - min)) * 7.0 + 0.5) as usize]
+            }
+        })
+        .collect()
+}
+
+/// Formats a number with 3 significant digits, switching to scientific notation for very small or large numbers.
+/// This is useful for formatting metrics such as loss.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::format_num(0.23456), "0.235");
+/// assert_eq!(format::format_num(12.345), "12.3");
+/// assert_eq!(format::format_num(0.00012345), "1.23e-4");
+/// assert_eq!(format::format_num(123456.0), "1.23e5");
+/// assert_eq!(format::format_num(1234.5), "1.23e3");
+/// assert_eq!(format::format_num(9.996), "10.0");
+/// assert_eq!(format::format_num(0.0), "0");
+/// ```
+pub fn format_num(num: f64) -> String {
+    if num == 0.0 || !num.is_finite() {
+        return num.to_string();
+    }
+
+    let abs = num.abs();
+
+    // Bounds are rounding points of 3 significant digits, so that i.e. 999.5 isn't rounded to 4 digits `1000`.
+    if !(0.9995e-3..999.5).contains(&abs) {
+        return format!("{:.2e}", num);
+    }
+
+    // Magnitude is counted using powers of ten, as `log10().floor()` isn't available without std.
+    let magnitude = [0.9995e-3, 0.9995e-2, 0.9995e-1, 0.9995, 9.995, 99.95]
+        .iter()
+        .filter(|&&power| abs >= power)
+        .count() as i32
+        - 4;
+
+    format!("{:.*}", (2 - magnitude).max(0) as usize, num)
 }
 
 /// Formats a number (greater than unity) with SI order of magnitude prefixes.
 pub fn format_sizeof(num: f64, divisor: f64) -> String {
+    format_sizeof_with(num, divisor, &SizeofOptions::default())
+}
+
+/// Formatting options of [format_sizeof_with](crate::format::format_sizeof_with).
+///
+/// Default options produce same output as [format_sizeof](crate::format::format_sizeof).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SizeofOptions {
+    /// Use IEC binary prefixes (`Ki`, `Mi`, ...) instead of SI prefixes, should be used with divisor of 1024.
+    pub binary: bool,
+    /// Use long prefix names (`kilo`, `mega`, ... or `kibi`, `mebi`, ...).
+    pub long: bool,
+    /// Number of decimal places, by default 3 significant digits are displayed.
+    pub precision: Option<usize>,
+    /// Insert a space between number and prefix, like `12.3 M`.
+    pub space: bool,
+}
+
+/// Formats a number (greater than unity) with order of magnitude prefixes using given `options`.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format::{self, SizeofOptions};
+///
+/// assert_eq!(format::format_sizeof_with(12.3e6, 1000., &SizeofOptions::default()), "12.3M");
+///
+/// let options = SizeofOptions { space: true, ..Default::default() };
+/// assert_eq!(format::format_sizeof_with(12.3e6, 1000., &options), "12.3 M");
+///
+/// let options = SizeofOptions {
+///     binary: true,
+///     precision: Some(0),
+///     space: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(format::format_sizeof_with(12.3 * 1024. * 1024., 1024., &options), "12 Mi");
+///
+/// let options = SizeofOptions { long: true, space: true, ..Default::default() };
+/// assert_eq!(format::format_sizeof_with(1.5e3, 1000., &options), "1.50 kilo");
+/// ```
+pub fn format_sizeof_with(num: f64, divisor: f64, options: &SizeofOptions) -> String {
+    let prefixes = match (options.binary, options.long) {
+        (false, false) => ["", "k", "M", "G", "T", "P", "E", "Z", "Y"],
+        (true, false) => ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"],
+        (false, true) => [
+            "", "kilo", "mega", "giga", "tera", "peta", "exa", "zetta", "yotta",
+        ],
+        (true, true) => [
+            "", "kibi", "mebi", "gibi", "tebi", "pebi", "exbi", "zebi", "yobi",
+        ],
+    };
+    let space = if options.space { " " } else { "" };
     let mut value = num;
 
-    for i in ["", "k", "M", "G", "T", "P", "E", "Z"] {
+    for i in &prefixes[..8] {
         if value.abs() < 999.5 {
-            if value.abs() < 99.95 {
-                if value.abs() < 9.995 {
-                    return format!("{:1.2}{}", value, i);
-                }
-                return format!("{:2.1}{}", value, i);
-            }
-            return format!("{:3.0}{}", value, i);
+            let precision = options.precision.unwrap_or(if value.abs() < 9.995 {
+                2
+            } else if value.abs() < 99.95 {
+                1
+            } else {
+                0
+            });
+
+            return format!("{:.*}{}{}", precision, value, space, i);
         }
         value /= divisor;
     }
-    format!("{:3.1}Y", value)
+
+    format!(
+        "{:.*}{}{}",
+        options.precision.unwrap_or(1),
+        value,
+        space,
+        prefixes[8]
+    )
 }
 
 pub fn format_time(num: f64) -> String {
@@ -36,7 +213,18 @@ pub fn format_time(num: f64) -> String {
     format!("{value:1.2}days")
 }
 
-/// Formats a number of seconds as a clock time, \[H:\]MM:SS and SSs.
+/// Formats a number of seconds as a clock time, \[D days, \]\[H:\]MM:SS and SSs.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::format_interval(62, false), "01:02");
+/// assert_eq!(format::format_interval(3723, false), "01:02:03");
+/// assert_eq!(format::format_interval(93784, false), "1 day, 02:03:04");
+/// assert_eq!(format::format_interval(180000, false), "2 days, 02:00:00");
+/// ```
 pub fn format_interval(seconds: usize, human: bool) -> String {
     if human && seconds < 60 {
         return seconds.to_string() + "s";
@@ -44,14 +232,165 @@ pub fn format_interval(seconds: usize, human: bool) -> String {
 
     let (minutes, seconds) = divmod(seconds, 60);
     let (hours, minutes) = divmod(minutes, 60);
+    let (days, hours) = divmod(hours, 24);
 
-    if hours == 0 {
+    if days != 0 {
+        format!(
+            "{} day{}, {:02}:{:02}:{:02}",
+            days,
+            if days == 1 { "" } else { "s" },
+            hours,
+            minutes,
+            seconds
+        )
+    } else if hours == 0 {
         format!("{:02}:{:02}", minutes, seconds)
     } else {
         format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
     }
 }
 
+/// Formats remaining time (ETA) using `format_interval`.
+/// ETAs longer than 7 days are displayed as `>7d`.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::format_eta(3723.0, false), "01:02:03");
+/// assert_eq!(format::format_eta(f32::INFINITY, false), ">7d");
+/// ```
+pub fn format_eta(seconds: f32, human: bool) -> String {
+    if !seconds.is_finite() || seconds >= 604800.0 {
+        ">7d".to_owned()
+    } else {
+        format_interval(seconds as usize, human)
+    }
+}
+
+/// Formats projected finish time as local clock time `HH:MM`, after given number of seconds from now.
+/// Finish times on later days are suffixed with number of days like `14:32+1d`.
+/// ETAs longer than 7 days are displayed as `>7d`.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format;
+///
+/// assert_eq!(format::format_finish_time(0.0).len(), 5);
+/// assert_eq!(format::format_finish_time(f32::INFINITY), ">7d");
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn format_finish_time(seconds: f32) -> String {
+    #[cfg(not(feature = "wasm"))]
+    use std::time::{SystemTime, UNIX_EPOCH};
+    #[cfg(feature = "wasm")]
+    use web_time::{SystemTime, UNIX_EPOCH};
+
+    if !seconds.is_finite() || seconds >= 604800.0 {
+        return ">7d".to_owned();
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs() as i64)
+        .unwrap_or(0)
+        + crate::term::get_utc_offset();
+    let finish = now + seconds as i64;
+    let days = finish.div_euclid(86400) - now.div_euclid(86400);
+    let (hours, minutes) = divmod(finish.rem_euclid(86400) as usize / 60, 60);
+
+    if days == 0 {
+        format!("{:02}:{:02}", hours, minutes)
+    } else {
+        format!("{:02}:{:02}+{}d", hours, minutes, days)
+    }
+}
+
+/// Truncates text to given display width (in graphemes), replacing removed part with an ellipsis.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format::{truncate, Truncate};
+///
+/// assert_eq!(truncate("hello world", 6, Truncate::Left), "…world");
+/// assert_eq!(truncate("hello world", 6, Truncate::Middle), "hel…ld");
+/// assert_eq!(truncate("hello world", 6, Truncate::Right), "hello…");
+/// assert_eq!(truncate("hello", 6, Truncate::Right), "hello");
+/// ```
+pub fn truncate(text: &str, width: usize, truncate: Truncate) -> String {
+    let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+    let len = graphemes.len();
+
+    if len <= width {
+        return text.to_owned();
+    }
+
+    if width == 0 {
+        return "".to_owned();
+    }
+
+    let keep = width - 1;
+
+    match truncate {
+        Truncate::Left => "…".to_owned() + &graphemes[(len - keep)..].concat(),
+        Truncate::Middle => {
+            let left = keep.div_ceil(2);
+            graphemes[..left].concat() + "…" + &graphemes[(len - (keep - left))..].concat()
+        }
+        Truncate::Right | Truncate::Marquee => graphemes[..keep].concat() + "…",
+    }
+}
+
+/// Returns a window of given display width (in graphemes) of text scrolled horizontally by `offset` graphemes.
+/// Text wraps around with a gap of three spaces. Text which fits in width is returned as it is.
+///
+/// # Example
+///
+/// ```
+/// use kdam::format::marquee;
+///
+/// assert_eq!(marquee("hello world", 6, 0), "hello ");
+/// assert_eq!(marquee("hello world", 6, 6), "world ");
+/// assert_eq!(marquee("hello world", 6, 11), "   hel");
+/// assert_eq!(marquee("hello", 6, 3), "hello");
+/// ```
+pub fn marquee(text: &str, width: usize, offset: usize) -> String {
+    let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+
+    if graphemes.len() <= width {
+        return text.to_owned();
+    }
+
+    let len = graphemes.len() + 3;
+
+    (0..width)
+        .map(|i| graphemes.get((offset + i) % len).copied().unwrap_or(" "))
+        .collect()
+}
+
+/// Formats ANSI trimmed `text` using `f` and restores ANSI escape codes of `text` in formatted text.
+/// This keeps width and alignment of template placeholders correct for coloured text.
+#[cfg(feature = "template")]
+pub(crate) fn format_ansi<F: FnOnce(&str) -> String>(text: &str, f: F) -> String {
+    use crate::term::Colorizer;
+
+    let plain = text.trim_ansi();
+    let formatted = f(&plain);
+
+    if plain.is_empty() || plain == text {
+        return formatted;
+    }
+
+    match formatted.find(&plain) {
+        Some(index) => formatted[..index].to_owned() + text + &formatted[(index + plain.len())..],
+        None => formatted,
+    }
+}
+
 // Intelligent scientific notation (.3g).
 // pub fn format_num(n: usize) -> String {
 //     let f = format!("{:.3g}", n)
@@ -60,4 +399,3 @@ pub fn format_interval(seconds: usize, human: bool) -> String {
 //     let n = format!("{}", n).to_string();
 //     return if f.len() < n.len() { f } else { n };
 // }
-
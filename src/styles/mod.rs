@@ -1,12 +1,22 @@
+//! Progress bar styles and animations.
+
 mod animation;
+#[cfg(feature = "std")]
+mod preview;
 
 #[cfg(feature = "spinner")]
 mod spinner;
+#[cfg(feature = "spinners")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spinners")))]
+pub mod spinners;
 
 pub mod format;
-pub mod rich;
+#[cfg(feature = "std")]
+pub(crate) mod rich;
 
 pub use animation::*;
+#[cfg(feature = "std")]
+pub use preview::{preview, preview_all};
 
 #[cfg(feature = "spinner")]
 #[cfg_attr(docsrs, doc(cfg(feature = "spinner")))]
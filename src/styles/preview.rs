@@ -0,0 +1,92 @@
+use crate::progress::{BarBuilder, BarExt, Column, RichProgress};
+use crate::styles::Animation;
+
+/// Render a progress bar with given animation from start to end in about one second.
+///
+/// # Example
+///
+/// ```no_run
+/// use kdam::Animation;
+///
+/// kdam::styles::preview(Animation::custom(&["\\", "|", "/", "-"]), "custom");
+/// ```
+pub fn preview<T: Into<String>>(animation: Animation, desc: T) {
+    let mut pb = BarBuilder::default()
+        .total(50)
+        .desc(desc)
+        .animation(animation)
+        .force_refresh(true)
+        .build()
+        .unwrap();
+
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_secs_f32(0.02));
+        pb.update(1);
+    }
+
+    eprintln!();
+}
+
+/// Render every built-in animation style, bouncing animation and rich style bar one after another.
+/// This is useful for choosing a style or smoke testing new styles.
+///
+/// # Example
+///
+/// ```no_run
+/// kdam::styles::preview_all();
+/// ```
+pub fn preview_all() {
+    let animations = [
+        ("tqdm     ", Animation::Tqdm),
+        ("ascii    ", Animation::TqdmAscii),
+        ("fillup   ", Animation::FillUp),
+        ("blocks   ", Animation::Blocks),
+        ("classic  ", Animation::Classic),
+        ("arrow    ", Animation::Arrow),
+        ("plain    ", Animation::Plain),
+        ("firacode ", Animation::FiraCode),
+        ("custom   ", Animation::custom(&["\\", "|", "/", "-"])),
+        (
+            "fill     ",
+            Animation::custom_with_fill(&["\\", "|", "/", "-"], "."),
+        ),
+    ];
+
+    for (desc, animation) in animations {
+        preview(animation, desc);
+    }
+
+    let mut pb = BarBuilder::default()
+        .desc("bounce   ")
+        .bounce(true)
+        .force_refresh(true)
+        .build()
+        .unwrap();
+
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_secs_f32(0.02));
+        pb.update(1);
+    }
+
+    eprintln!();
+
+    let mut pb = RichProgress::new(
+        BarBuilder::default()
+            .total(50)
+            .force_refresh(true)
+            .build()
+            .unwrap(),
+        vec![
+            Column::text("rich     "),
+            Column::Bar,
+            Column::Percentage(1),
+        ],
+    );
+
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_secs_f32(0.02));
+        pb.update(1);
+    }
+
+    eprintln!();
+}
@@ -1,13 +1,18 @@
 use crate::term::Colorizer;
 
 /// Panics ->  capacity overflow, if ncols == 0
-pub(crate) fn bar(progress: f32, ncols: i16) -> String {
+///
+/// If `accessible` is true, remaining part is drawn with thin lines so that it
+/// can be distinguished from completed part without relying on colours.
+pub(crate) fn bar(progress: f32, ncols: i16, accessible: bool) -> String {
     if progress >= 1.0 {
         "━".repeat(ncols as usize).colorize("#729c1f")
     } else {
         let block = (ncols as f32 * progress) as i16;
         ("━".repeat(block as usize) + "╸").colorize("#F92672")
-            + &"━".repeat((ncols - block - 1) as usize).colorize("#525252")
+            + &(if accessible { "─" } else { "━" })
+                .repeat((ncols - block - 1) as usize)
+                .colorize("#525252")
     }
 }
 
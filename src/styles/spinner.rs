@@ -38,6 +38,32 @@ impl Spinner {
         }
     }
 
+    /// Create a spinner from [spinners](crate::spinners) data set by its `name`, if it exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::Spinner;
+    ///
+    /// let spinner = Spinner::known("dots12").unwrap();
+    /// assert_eq!(spinner.render_frame(0.0), "⢀⠀");
+    /// assert!(Spinner::known("unknown").is_none());
+    /// ```
+    #[cfg(feature = "spinners")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "spinners")))]
+    pub fn known(name: &str) -> Option<Self> {
+        crate::spinners::get(name).map(|(interval, frames)| Self::new(frames, interval, 1.0))
+    }
+
+    /// Returns ASCII only version of spinner, frames containing non ASCII glyphs are replaced with `-\|/` frames.
+    pub(crate) fn ascii(&self) -> Self {
+        if self.frames.iter().all(|x| x.is_ascii()) {
+            return self.clone();
+        }
+
+        Self::new(&["-", "\\", "|", "/"], self.interval, self.speed)
+    }
+
     /// Render single frame of spinner.
     pub fn render_frame(&self, elapsed_time: f32) -> String {
         let frame_no = (elapsed_time * self.speed) / (self.interval / 1000.0);
@@ -0,0 +1,238 @@
+//! Ready to use spinners imported from [cli-spinners](https://github.com/sindresorhus/cli-spinners) collection.
+//!
+//! Each spinner has a name, interval of frames in milliseconds and frames.
+//! Spinners can be created by name using [Spinner::known](crate::Spinner::known).
+//!
+//! ```
+//! use kdam::spinners;
+//!
+//! let (interval, frames) = spinners::get("dots12").unwrap();
+//! assert_eq!(interval, 80.0);
+//! assert_eq!(frames.len(), 56);
+//! assert!(spinners::names().any(|x| x == "bouncingBar"));
+//! ```
+//!
+//! # License
+//!
+//! Spinners data is taken from cli-spinners, which is distributed under following license.
+//!
+//! ```text
+//! MIT License
+//!
+//! Copyright (c) Sindre Sorhus <sindresorhus@gmail.com> (https://sindresorhus.com)
+//!
+//! Permission is hereby granted, free of charge, to any person obtaining a copy of this software and
+//! associated documentation files (the "Software"), to deal in the Software without restriction, including
+//! without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+//! copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the
+//! following conditions:
+//!
+//! The above copyright notice and this permission notice shall be included in all copies or substantial
+//! portions of the Software.
+//!
+//! THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT
+//! LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO
+//! EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+//! IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE
+//! USE OR OTHER DEALINGS IN THE SOFTWARE.
+//! ```
+
+/// Spinners as `(name, interval, frames)`.
+static SPINNERS: &[(&str, f32, &[&str])] = &[
+    (
+        "dots",
+        80.0,
+        &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+    ),
+    ("dots2", 80.0, &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"]),
+    (
+        "dots3",
+        80.0,
+        &["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"],
+    ),
+    (
+        "dots4",
+        80.0,
+        &[
+            "⠄", "⠆", "⠇", "⠋", "⠙", "⠸", "⠰", "⠠", "⠰", "⠸", "⠙", "⠋", "⠇", "⠆",
+        ],
+    ),
+    (
+        "dots5",
+        80.0,
+        &[
+            "⠋", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋",
+        ],
+    ),
+    (
+        "dots6",
+        80.0,
+        &[
+            "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠴", "⠲", "⠒",
+            "⠂", "⠂", "⠒", "⠚", "⠙", "⠉", "⠁",
+        ],
+    ),
+    (
+        "dots7",
+        80.0,
+        &[
+            "⠈", "⠉", "⠋", "⠓", "⠒", "⠐", "⠐", "⠒", "⠖", "⠦", "⠤", "⠠", "⠠", "⠤", "⠦", "⠖", "⠒",
+            "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈",
+        ],
+    ),
+    (
+        "dots8",
+        80.0,
+        &[
+            "⠁", "⠁", "⠉", "⠙", "⠚", "⠒", "⠂", "⠂", "⠒", "⠲", "⠴", "⠤", "⠄", "⠄", "⠤", "⠠", "⠠",
+            "⠤", "⠦", "⠖", "⠒", "⠐", "⠐", "⠒", "⠓", "⠋", "⠉", "⠈", "⠈",
+        ],
+    ),
+    ("dots9", 80.0, &["⢹", "⢺", "⢼", "⣸", "⣇", "⡧", "⡗", "⡏"]),
+    ("dots10", 80.0, &["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"]),
+    ("dots11", 100.0, &["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"]),
+    (
+        "dots12",
+        80.0,
+        &[
+            "⢀⠀", "⡀⠀", "⠄⠀", "⢂⠀", "⡂⠀", "⠅⠀", "⢃⠀", "⡃⠀", "⠍⠀", "⢋⠀", "⡋⠀", "⠍⠁", "⢋⠁", "⡋⠁",
+            "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩", "⠈⢙", "⠈⡙", "⢈⠩", "⡀⢙", "⠄⡙", "⢂⠩", "⡂⢘", "⠅⡘",
+            "⢃⠨", "⡃⢐", "⠍⡐", "⢋⠠", "⡋⢀", "⠍⡁", "⢋⠁", "⡋⠁", "⠍⠉", "⠋⠉", "⠋⠉", "⠉⠙", "⠉⠙", "⠉⠩",
+            "⠈⢙", "⠈⡙", "⠈⠩", "⠀⢙", "⠀⡙", "⠀⠩", "⠀⢘", "⠀⡘", "⠀⠨", "⠀⢐", "⠀⡐", "⠀⠠", "⠀⢀", "⠀⡀",
+        ],
+    ),
+    ("line", 130.0, &["-", "\\", "|", "/"]),
+    ("line2", 100.0, &["⠂", "-", "–", "—", "–", "-"]),
+    ("pipe", 100.0, &["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"]),
+    ("simpleDots", 400.0, &[".  ", ".. ", "...", "   "]),
+    (
+        "simpleDotsScrolling",
+        200.0,
+        &[".  ", ".. ", "...", " ..", "  .", "   "],
+    ),
+    ("star", 70.0, &["✶", "✸", "✹", "✺", "✹", "✷"]),
+    ("star2", 80.0, &["+", "x", "*"]),
+    (
+        "flip",
+        70.0,
+        &["_", "_", "_", "-", "`", "`", "'", "´", "-", "_", "_", "_"],
+    ),
+    ("hamburger", 100.0, &["☱", "☲", "☴"]),
+    (
+        "growVertical",
+        120.0,
+        &["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+    ),
+    (
+        "growHorizontal",
+        120.0,
+        &["▏", "▎", "▍", "▌", "▋", "▊", "▉", "▊", "▋", "▌", "▍", "▎"],
+    ),
+    ("balloon", 140.0, &[" ", ".", "o", "O", "@", "*", " "]),
+    ("balloon2", 120.0, &[".", "o", "O", "°", "O", "o", "."]),
+    ("noise", 100.0, &["▓", "▒", "░"]),
+    ("bounce", 120.0, &["⠁", "⠂", "⠄", "⠂"]),
+    ("boxBounce", 120.0, &["▖", "▘", "▝", "▗"]),
+    ("boxBounce2", 100.0, &["▌", "▀", "▐", "▄"]),
+    ("triangle", 50.0, &["◢", "◣", "◤", "◥"]),
+    ("arc", 100.0, &["◜", "◠", "◝", "◞", "◡", "◟"]),
+    ("circle", 120.0, &["◡", "⊙", "◠"]),
+    ("squareCorners", 180.0, &["◰", "◳", "◲", "◱"]),
+    ("circleQuarters", 120.0, &["◴", "◷", "◶", "◵"]),
+    ("circleHalves", 50.0, &["◐", "◓", "◑", "◒"]),
+    ("squish", 100.0, &["╫", "╪"]),
+    ("toggle", 250.0, &["⊶", "⊷"]),
+    ("toggle2", 80.0, &["▫", "▪"]),
+    ("toggle3", 120.0, &["□", "■"]),
+    ("arrow", 100.0, &["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"]),
+    (
+        "arrow3",
+        120.0,
+        &["▹▹▹▹▹", "▸▹▹▹▹", "▹▸▹▹▹", "▹▹▸▹▹", "▹▹▹▸▹", "▹▹▹▹▸"],
+    ),
+    (
+        "bouncingBar",
+        80.0,
+        &[
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]",
+            "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+        ],
+    ),
+    (
+        "bouncingBall",
+        80.0,
+        &[
+            "( ●    )",
+            "(  ●   )",
+            "(   ●  )",
+            "(    ● )",
+            "(     ●)",
+            "(    ● )",
+            "(   ●  )",
+            "(  ●   )",
+            "( ●    )",
+            "(●     )",
+        ],
+    ),
+    ("smiley", 200.0, &["😄 ", "😝 "]),
+    ("monkey", 300.0, &["🙈 ", "🙈 ", "🙉 ", "🙊 "]),
+    ("hearts", 100.0, &["💛 ", "💙 ", "💜 ", "💚 ", "❤️ "]),
+    (
+        "clock",
+        100.0,
+        &[
+            "🕛 ", "🕐 ", "🕑 ", "🕒 ", "🕓 ", "🕔 ", "🕕 ", "🕖 ", "🕗 ", "🕘 ", "🕙 ", "🕚 ",
+        ],
+    ),
+    ("earth", 180.0, &["🌍 ", "🌎 ", "🌏 "]),
+    (
+        "moon",
+        80.0,
+        &["🌑 ", "🌒 ", "🌓 ", "🌔 ", "🌕 ", "🌖 ", "🌗 ", "🌘 "],
+    ),
+    ("runner", 140.0, &["🚶 ", "🏃 "]),
+    ("christmas", 400.0, &["🌲", "🎄"]),
+    (
+        "aesthetic",
+        80.0,
+        &[
+            "▰▱▱▱▱▱▱",
+            "▰▰▱▱▱▱▱",
+            "▰▰▰▱▱▱▱",
+            "▰▰▰▰▱▱▱",
+            "▰▰▰▰▰▱▱",
+            "▰▰▰▰▰▰▱",
+            "▰▰▰▰▰▰▰",
+            "▰▱▱▱▱▱▱",
+        ],
+    ),
+    ("point", 125.0, &["∙∙∙", "●∙∙", "∙●∙", "∙∙●", "∙∙∙"]),
+    ("layer", 150.0, &["-", "=", "≡"]),
+    (
+        "betaWave",
+        80.0,
+        &[
+            "ρββββββ",
+            "βρβββββ",
+            "ββρββββ",
+            "βββρβββ",
+            "ββββρββ",
+            "βββββρβ",
+            "ββββββρ",
+        ],
+    ),
+    ("dqpb", 100.0, &["d", "q", "p", "b"]),
+];
+
+/// Returns interval (in milliseconds) and frames of spinner with `name`, if it exists.
+pub fn get(name: &str) -> Option<(f32, &'static [&'static str])> {
+    SPINNERS
+        .iter()
+        .find(|(x, _, _)| *x == name)
+        .map(|(_, interval, frames)| (*interval, *frames))
+}
+
+/// Returns names of all available spinners.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    SPINNERS.iter().map(|(name, _, _)| *name)
+}
@@ -1,6 +1,14 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "gradient")]
 use unicode_segmentation::UnicodeSegmentation;
 
-#[cfg(target_os = "windows")]
+#[cfg(all(feature = "std", target_os = "windows"))]
 static COLOURS_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 
 /// Create ANSI colour escape code from primary colours or hex colour code or rgb(r,g,b).
@@ -14,7 +22,7 @@ static COLOURS_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::Atomi
 /// assert_eq!(colour("blue on white"), "\x1b[34;47m");
 /// ```
 pub fn colour(colour_code: &str) -> String {
-    #[cfg(target_os = "windows")]
+    #[cfg(all(feature = "std", target_os = "windows"))]
     if !COLOURS_ENABLED.load(std::sync::atomic::Ordering::Acquire) {
         std::process::Command::new("cmd")
             .args(["/c", "color"])
@@ -49,6 +57,9 @@ pub fn colour(colour_code: &str) -> String {
             "",
         );
         format!(";48;2;{};{};{}", rgb[0], rgb[1], rgb[2])
+    } else if let Some((index, len, name)) = find_bright_background(&color) {
+        color.replace_range(index..(index + len), "");
+        format!(";{}", 100 + name)
     } else if color.contains("ON BLACK") {
         color = color.replace("ON BLACK", "");
         ";40".to_owned()
@@ -169,6 +180,298 @@ pub fn colour(colour_code: &str) -> String {
     code
 }
 
+/// Returns index, length and palette index of bright background colour (i.e. `on bright black`) in uppercase colour code.
+fn find_bright_background(color: &str) -> Option<(usize, usize, usize)> {
+    let index = color.find("ON BRIGHT")?;
+    let rest = &color[(index + 9)..];
+    let name = rest.trim_start_matches([' ', '_']);
+
+    PALETTE.iter().enumerate().find_map(|(i, x)| {
+        name.starts_with(&x.to_uppercase())
+            .then(|| (index, 9 + rest.len() - name.len() + x.len(), i))
+    })
+}
+
+/// Names of colours supported by [Colour](crate::term::Colour), all of them can be prefixed with `bright`.
+const PALETTE: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// Names of text styles supported by [Colour](crate::term::Colour).
+const STYLES: [&str; 8] = [
+    "bold",
+    "dim",
+    "italic",
+    "underline",
+    "blink",
+    "reversed",
+    "hidden",
+    "strikethrough",
+];
+
+/// Validated colour code accepted by [colour](crate::term::colour) or `default` or `gradient(...)` colour.
+///
+/// Unlike [colour](crate::term::colour), which silently ignores unknown names,
+/// [parse](Self::parse) returns an error explaining what is wrong with colour code.
+///
+/// # Example
+///
+/// ```
+/// use kdam::term::Colour;
+///
+/// let colour = Colour::parse("bold bright red on #1a1a1a").unwrap();
+/// assert_eq!(colour.escape_code(), "\x1b[91;48;2;26;26;26;1m");
+/// assert_eq!(Colour::parse("BRIGHT_RED").unwrap().escape_code(), "\x1b[91m");
+/// assert_eq!(Colour::parse("#ff0000 on bright black").unwrap().escape_code(), "\x1b[38;2;255;0;0;100m");
+///
+/// let error = Colour::parse("purple").unwrap_err();
+/// assert_eq!(error.colour(), "purple");
+/// assert!(error.to_string().contains("unknown colour name \"purple\""));
+///
+/// assert!(Colour::named_palette().contains(&"magenta"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Colour {
+    code: String,
+}
+
+impl Colour {
+    /// Parse and validate colour code.
+    /// Colour code is case insensitive and consists of an optional style (e.g. `bold`), a foreground colour
+    /// (named colour, `#rrggbb` or `rgb(r,g,b)`) and an optional background colour prefixed with `on`.
+    /// Named colours can be prefixed with `bright`, `bright_` or `bright ` same as [colour](crate::term::colour).
+    pub fn parse(colour_code: &str) -> Result<Self, ColourError> {
+        let error = |message: String| {
+            Err(ColourError {
+                colour: colour_code.to_owned(),
+                message,
+            })
+        };
+        let code = colour_code.trim().to_lowercase();
+
+        if code == "default" {
+            return Ok(Self {
+                code: colour_code.to_owned(),
+            });
+        }
+
+        if let Some(stops) = code.strip_prefix("gradient(") {
+            let stops = match stops.strip_suffix(')') {
+                Some(stops) => stops,
+                None => return error("gradient is missing closing parenthesis".to_owned()),
+            };
+
+            for stop in stops.split(',').map(|x| x.trim()) {
+                if stop.is_empty() {
+                    return error("gradient contains an empty colour stop".to_owned());
+                }
+
+                if stop.starts_with('#') && !is_hex(stop) {
+                    return error(format!(
+                        "invalid hex colour code {:?} in gradient, expected #rrggbb",
+                        stop
+                    ));
+                }
+            }
+
+            return Ok(Self {
+                code: colour_code.to_owned(),
+            });
+        }
+
+        let mut foreground = false;
+        let mut background = false;
+        let mut on = false;
+        let mut bright = false;
+
+        // Underscores and missing spaces after bright are accepted by colour, i.e. bright_red or brightred.
+        let code = code.replace('_', " ");
+        let tokens = tokenize(&code).into_iter().flat_map(|token| {
+            match token.strip_prefix("bright").filter(|x| !x.is_empty()) {
+                Some(name) => vec!["bright", name],
+                None => vec![token],
+            }
+        });
+
+        for token in tokens {
+            if token == "on" {
+                if on || !foreground {
+                    return error("expected `on` after foreground colour".to_owned());
+                }
+
+                on = true;
+                continue;
+            }
+
+            if STYLES.contains(&token) {
+                continue;
+            }
+
+            if token == "bright" {
+                bright = true;
+                continue;
+            }
+
+            if token.starts_with('#') {
+                if !is_hex(token) {
+                    return error(format!(
+                        "invalid hex colour code {:?}, expected #rrggbb",
+                        token
+                    ));
+                }
+            } else if let Some(rgb) = token.strip_prefix("rgb(") {
+                let valid = rgb.strip_suffix(')').is_some_and(|rgb| {
+                    let rgb = rgb.split(',').map(|x| x.trim().parse::<u8>());
+                    rgb.clone().count() == 3 && rgb.into_iter().all(|x| x.is_ok())
+                });
+
+                if !valid {
+                    return error(format!(
+                        "invalid rgb colour {:?}, expected rgb(r,g,b) with values between 0 and 255",
+                        token
+                    ));
+                }
+            } else if !PALETTE.contains(&token) {
+                return error(format!(
+                    "unknown colour name {:?}, supported names are {} (optionally prefixed with bright) \
+                     and supported styles are {}",
+                    token,
+                    PALETTE.join(", "),
+                    STYLES.join(", ")
+                ));
+            }
+
+            if bright && (token.starts_with('#') || token.starts_with("rgb(")) {
+                return error("bright can only be used with named colours".to_owned());
+            }
+
+            bright = false;
+
+            if on {
+                if background {
+                    return error("background colour is given more than once".to_owned());
+                }
+
+                background = true;
+            } else {
+                if foreground {
+                    return error(
+                        "foreground colour is given more than once, use `on` for background colour"
+                            .to_owned(),
+                    );
+                }
+
+                foreground = true;
+            }
+        }
+
+        if !foreground {
+            return error("missing foreground colour".to_owned());
+        }
+
+        if on && !background {
+            return error("missing background colour after `on`".to_owned());
+        }
+
+        Ok(Self {
+            code: colour_code.to_owned(),
+        })
+    }
+
+    /// Returns names of colours, which can be used in colour codes.
+    pub fn named_palette() -> &'static [&'static str] {
+        &PALETTE
+    }
+
+    /// Returns colour code as it was given.
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns ANSI colour escape code, see [colour](crate::term::colour).
+    /// Escape code is empty for `default` colour.
+    pub fn escape_code(&self) -> String {
+        colour(&self.code)
+    }
+}
+
+impl core::str::FromStr for Colour {
+    type Err = ColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl core::fmt::Display for Colour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.code)
+    }
+}
+
+/// Error returned by [Colour::parse](crate::term::Colour::parse), explaining why colour code is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColourError {
+    colour: String,
+    message: String,
+}
+
+impl ColourError {
+    /// Returns invalid colour code.
+    pub fn colour(&self) -> &str {
+        &self.colour
+    }
+}
+
+impl core::fmt::Display for ColourError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid colour {:?}: {}", self.colour, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColourError {}
+
+impl From<ColourError> for crate::Error {
+    fn from(error: ColourError) -> Self {
+        Self::InvalidColour(error)
+    }
+}
+
+/// Returns wheter `code` is a hex colour code of form `#rrggbb`.
+fn is_hex(code: &str) -> bool {
+    code.len() == 7 && code[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Split colour code by whitespace, keeping whitespace inside parentheses i.e. `rgb(1, 2, 3)` as a single token.
+fn tokenize(code: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut depth = 0;
+    let mut start = None;
+
+    for (index, c) in code.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+
+        if c.is_whitespace() && depth <= 0 {
+            if let Some(start) = start.take() {
+                tokens.push(&code[start..index]);
+            }
+        } else if start.is_none() {
+            start = Some(index);
+        }
+    }
+
+    if let Some(start) = start {
+        tokens.push(&code[start..]);
+    }
+
+    tokens
+}
+
 /// Add ANSI colour escape codes to the given text for printing coloured text in terminal.
 /// This trait is only implemented for `&str`.
 pub trait Colorizer {
@@ -227,10 +530,31 @@ pub trait Colorizer {
     fn gradient_text(&self, codes: &[&str]) -> String;
 
     /// Inverse of colorize method.
-    /// This method trims all ANSI escape codes from given string.
+    /// This method trims all ANSI escape sequences (CSI sequences like colours and cursor movements,
+    /// OSC sequences like hyperlinks and other two character sequences) from given string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::Colorizer;
+    ///
+    /// assert_eq!("\x1b[1;31mhello\x1b[0m \x1b[2Kworld".trim_ansi(), "hello world");
+    /// assert_eq!("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\".trim_ansi(), "link");
+    /// ```
     fn trim_ansi(&self) -> String;
 
-    /// Returns terminal display length of string using graphemes.
+    /// Returns terminal display width of string, ignoring ANSI escape sequences.
+    /// Wide glyphs such as emoji and CJK characters occupy two columns.
+    /// This is used for layout of progress bars, so coloured text and wide glyphs don't break alignment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::term::Colorizer;
+    ///
+    /// assert_eq!("hello".colorize("bold red").len_ansi(), 5);
+    /// assert_eq!("🟩🟩⬜".len_ansi(), 6);
+    /// ```
     fn len_ansi(&self) -> usize;
 }
 
@@ -273,19 +597,48 @@ impl Colorizer for str {
     }
 
     fn trim_ansi(&self) -> String {
-        let mut text = self.replace("\x1b[0m", "");
+        let mut text = String::with_capacity(self.len());
+        let mut chars = self.chars().peekable();
 
-        while let Some(start) = text.find("\x1b[") {
-            text = text.replace(
-                &text[start..(start + text[start..].find('m').unwrap() + 1)],
-                "",
-            );
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                text.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                // CSI sequence, ends with a byte in range `@` to `~`.
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC sequence, ends with BEL or ST (`ESC \`).
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+
+                        if c == '\x1b' {
+                            if chars.peek() == Some(&'\\') {
+                                chars.next();
+                            }
+
+                            break;
+                        }
+                    }
+                }
+                _ => (),
+            }
         }
 
         text
     }
 
     fn len_ansi(&self) -> usize {
-        self.trim_ansi().graphemes(true).count()
+        unicode_width::UnicodeWidthStr::width(self.trim_ansi().as_str())
     }
 }
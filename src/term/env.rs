@@ -1,7 +1,131 @@
 /// Get number of columns in current window or default to specified value.
 pub fn get_columns_or(width: u16) -> u16 {
-    terminal_size::terminal_size()
-        .unwrap_or((terminal_size::Width(width), terminal_size::Height(0)))
-        .0
-         .0
+    #[cfg(all(any(unix, windows), not(target_arch = "wasm32")))]
+    {
+        terminal_size::terminal_size()
+            .unwrap_or((terminal_size::Width(width), terminal_size::Height(0)))
+            .0
+             .0
+    }
+
+    #[cfg(not(all(any(unix, windows), not(target_arch = "wasm32"))))]
+    {
+        width
+    }
+}
+
+/// Get number of rows in current window or default to specified value.
+pub(crate) fn get_rows_or(height: u16) -> u16 {
+    #[cfg(all(any(unix, windows), not(target_arch = "wasm32")))]
+    {
+        terminal_size::terminal_size()
+            .unwrap_or((terminal_size::Width(0), terminal_size::Height(height)))
+            .1
+             .0
+    }
+
+    #[cfg(not(all(any(unix, windows), not(target_arch = "wasm32"))))]
+    {
+        height
+    }
+}
+
+/// Get current offset of local timezone from UTC in seconds, which is queried on every call so that
+/// daylight saving changes are taken into account. Offset is `0` on unsupported platforms or if query fails.
+pub(crate) fn get_utc_offset() -> i64 {
+    #[cfg(unix)]
+    {
+        let now = unsafe { libc::time(std::ptr::null_mut()) };
+        let mut local = std::mem::MaybeUninit::<libc::tm>::uninit();
+        let mut utc = std::mem::MaybeUninit::<libc::tm>::uninit();
+
+        if unsafe { libc::localtime_r(&now, local.as_mut_ptr()) }.is_null()
+            || unsafe { libc::gmtime_r(&now, utc.as_mut_ptr()) }.is_null()
+        {
+            return 0;
+        }
+
+        let (local, utc) = unsafe { (local.assume_init(), utc.assume_init()) };
+        // Local date is at most one day apart from UTC date, year changes if they are on different sides of new year.
+        let days = if local.tm_year != utc.tm_year {
+            (local.tm_year - utc.tm_year).signum()
+        } else {
+            local.tm_yday - utc.tm_yday
+        };
+
+        i64::from(days) * 86400
+            + i64::from(local.tm_hour - utc.tm_hour) * 3600
+            + i64::from(local.tm_min - utc.tm_min) * 60
+            + i64::from(local.tm_sec - utc.tm_sec)
+    }
+
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::System::SystemServices::TIME_ZONE_ID_DAYLIGHT;
+        use windows_sys::Win32::System::Time::{GetTimeZoneInformation, TIME_ZONE_INFORMATION};
+
+        let mut info = unsafe { std::mem::zeroed::<TIME_ZONE_INFORMATION>() };
+
+        // Bias is in minutes and UTC = local time + bias.
+        let bias = match unsafe { GetTimeZoneInformation(&mut info) } {
+            u32::MAX => return 0,
+            TIME_ZONE_ID_DAYLIGHT => info.Bias + info.DaylightBias,
+            _ => info.Bias + info.StandardBias,
+        };
+
+        -i64::from(bias) * 60
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        0
+    }
+}
+
+/// Returns wheter terminal supports progress reporting using `OSC 9;4` escape sequences or not.
+/// Support is detected for Windows Terminal, ConEmu, iTerm2, WezTerm and Ghostty.
+pub fn supports_taskbar_progress() -> bool {
+    if std::env::var_os("WT_SESSION").is_some() {
+        return true;
+    }
+
+    if std::env::var("ConEmuANSI").is_ok_and(|x| x == "ON") {
+        return true;
+    }
+
+    matches!(
+        std::env::var("TERM_PROGRAM").as_deref(),
+        Ok("iTerm.app") | Ok("WezTerm") | Ok("ghostty")
+    )
+}
+
+/// Returns wheter terminal can display unicode glyphs or not.
+/// Support is detected using `TERM` (`dumb` terminals don't support it) and
+/// `LC_ALL`, `LC_CTYPE` or `LANG` locale variables, which should use `UTF-8` encoding.
+/// Windows terminals are assumed to support unicode.
+///
+/// # Example
+///
+/// ```
+/// use kdam::tqdm;
+///
+/// let pb = tqdm!(total = 100, ascii = !kdam::term::supports_unicode());
+/// ```
+pub fn supports_unicode() -> bool {
+    if std::env::var("TERM").is_ok_and(|x| x == "dumb") {
+        return false;
+    }
+
+    if cfg!(windows) {
+        return true;
+    }
+
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|x| std::env::var(x).ok())
+        .find(|x| !x.is_empty())
+        .is_some_and(|x| {
+            let x = x.to_lowercase();
+            x.contains("utf-8") || x.contains("utf8")
+        })
 }
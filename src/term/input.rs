@@ -0,0 +1,301 @@
+use std::time::Duration;
+#[cfg(unix)]
+use std::time::Instant;
+
+/// Read a line from stdin with terminal echo turned off, useful for reading secrets.
+/// Echo is turned off using termios on unix platforms, input which is not connected to a terminal (i.e. a pipe)
+/// is never echoed, so it is read as it is. On other platforms echo can't be turned off, so an
+/// [Unsupported](std::io::ErrorKind::Unsupported) error is returned instead of echoing secret.
+pub(crate) fn read_line_masked() -> std::io::Result<String> {
+    #[cfg(unix)]
+    {
+        let echo = if unix::is_terminal() {
+            Some(unix::Termios::set(|x| x.c_lflag &= !libc::ECHO)?)
+        } else {
+            None
+        };
+
+        let mut input_string = String::new();
+        let result = std::io::stdin().read_line(&mut input_string);
+
+        if let Some(echo) = echo {
+            echo.restore()?;
+            // Newline is not echoed back by terminal.
+            eprintln!();
+        }
+
+        result?;
+        Ok(input_string)
+    }
+
+    #[cfg(not(unix))]
+    Err(unsupported("masked input"))
+}
+
+/// Read a line from stdin, calling `tick` after every `interval` while waiting.
+/// Returns `None` if no line is read within `timeout`.
+///
+/// Stdin is polled without a background thread, so input typed after timeout isn't lost and can be read as usual.
+/// On platforms other than unix an [Unsupported](std::io::ErrorKind::Unsupported) error is returned.
+pub(crate) fn read_line_timeout<F: FnMut()>(
+    timeout: Duration,
+    interval: Duration,
+    mut tick: F,
+) -> std::io::Result<Option<String>> {
+    #[cfg(unix)]
+    {
+        let start = Instant::now();
+
+        loop {
+            let remaining = timeout.saturating_sub(start.elapsed());
+
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+
+            if unix::poll(remaining.min(interval))? {
+                return unix::read_line().map(Some);
+            }
+
+            tick();
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (timeout, interval, &mut tick);
+        Err(unsupported("input with timeout"))
+    }
+}
+
+/// Terminal input mode in which key presses are available immediately and aren't echoed,
+/// so that interactive features (e.g. pause on `p`, quit on `q`) can be layered on bars.
+/// Previous mode is restored when [RawMode](crate::term::RawMode) is dropped.
+///
+/// Unlike a full raw mode, output processing and signals are left untouched,
+/// so bars render as usual and `Ctrl+C` still interrupts the program.
+/// Mode is changed using termios on unix platforms, on other platforms this does nothing.
+///
+/// # Example
+///
+/// ```no_run
+/// use kdam::{term::RawMode, tqdm, BarExt};
+/// use std::time::Duration;
+///
+/// let _raw = RawMode::enter().unwrap();
+/// let mut pb = tqdm!(total = 100);
+///
+/// while !pb.completed() {
+///     match kdam::term::poll_key(Duration::from_millis(50)) {
+///         Some('q') => break,
+///         Some('p') => while kdam::term::poll_key(Duration::from_millis(50)) != Some('p') {},
+///         _ => {
+///             pb.update(1);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RawMode {
+    #[cfg(unix)]
+    previous: Option<unix::Termios>,
+}
+
+impl RawMode {
+    /// Enter raw mode, previous mode is restored when returned guard is dropped.
+    pub fn enter() -> std::io::Result<Self> {
+        #[cfg(unix)]
+        {
+            let previous = unix::Termios::set(|x| {
+                x.c_lflag &= !(libc::ICANON | libc::ECHO);
+                x.c_cc[libc::VMIN] = 1;
+                x.c_cc[libc::VTIME] = 0;
+            })?;
+
+            Ok(Self {
+                previous: Some(previous),
+            })
+        }
+
+        #[cfg(not(unix))]
+        Ok(Self {})
+    }
+
+    /// Restore previous mode of terminal.
+    pub fn exit(self) -> std::io::Result<()> {
+        #[cfg(unix)]
+        if let Some(previous) = &self.previous {
+            previous.restore()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Wait upto `timeout` for a key press and return it, returns `None` if no key is pressed within `timeout`.
+///
+/// Stdin is polled without a background thread, so stdin can be read as usual between calls.
+/// Terminal should be in [RawMode](crate::term::RawMode), otherwise keys are only available after `Enter` is pressed.
+/// On platforms other than unix this does nothing, it waits for `timeout` and returns `None`.
+pub fn poll_key(timeout: Duration) -> Option<char> {
+    #[cfg(unix)]
+    {
+        if !unix::poll(timeout).ok()? {
+            return None;
+        }
+
+        let Some(first) = unix::read_byte().ok()? else {
+            // Stdin is closed, so it is always readable.
+            std::thread::sleep(timeout);
+            return None;
+        };
+
+        let len = match first {
+            0x00..=0x7F => 1,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return None,
+        };
+        let mut buf = vec![first];
+
+        while buf.len() < len {
+            buf.push(unix::read_byte().ok()??);
+        }
+
+        std::str::from_utf8(&buf).ok()?.chars().next()
+    }
+
+    #[cfg(not(unix))]
+    {
+        std::thread::sleep(timeout);
+        None
+    }
+}
+
+#[cfg(not(unix))]
+fn unsupported(feature: &str) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("{} is only supported on unix platforms", feature),
+    )
+}
+
+/// Polling, reading and mode changes of stdin using file descriptor directly.
+#[cfg(unix)]
+mod unix {
+    use std::io::{Error, ErrorKind, Result};
+    use std::time::Duration;
+
+    /// Returns wheter stdin is connected to a terminal or not.
+    pub(super) fn is_terminal() -> bool {
+        unsafe { libc::isatty(libc::STDIN_FILENO) == 1 }
+    }
+
+    /// Wait upto `timeout` for stdin to become readable.
+    pub(super) fn poll(timeout: Duration) -> Result<bool> {
+        let mut fd = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // Rounded up, so that sub millisecond timeouts don't busy loop.
+        let timeout = timeout
+            .as_nanos()
+            .div_ceil(1_000_000)
+            .min(libc::c_int::MAX as u128) as libc::c_int;
+
+        match unsafe { libc::poll(&mut fd, 1, timeout) } {
+            -1 => {
+                let error = Error::last_os_error();
+
+                if error.kind() == ErrorKind::Interrupted {
+                    Ok(false)
+                } else {
+                    Err(error)
+                }
+            }
+            0 => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    /// Read a single byte from stdin, returns `None` at end of input.
+    pub(super) fn read_byte() -> Result<Option<u8>> {
+        let mut byte = 0_u8;
+
+        loop {
+            match unsafe { libc::read(libc::STDIN_FILENO, &mut byte as *mut u8 as *mut _, 1) } {
+                1 => return Ok(Some(byte)),
+                0 => return Ok(None),
+                _ => {
+                    let error = Error::last_os_error();
+
+                    if error.kind() != ErrorKind::Interrupted {
+                        return Err(error);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Read a line from stdin byte by byte, so that nothing after newline is consumed.
+    pub(super) fn read_line() -> Result<String> {
+        let mut buf = vec![];
+
+        while let Some(byte) = read_byte()? {
+            buf.push(byte);
+
+            if byte == b'\n' {
+                break;
+            }
+        }
+
+        String::from_utf8(buf).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Previous terminal attributes of stdin, which are restored when dropped.
+    pub(super) struct Termios(libc::termios);
+
+    impl Termios {
+        /// Modify terminal attributes of stdin using `f`, returns guard holding previous attributes.
+        pub(super) fn set<F: FnOnce(&mut libc::termios)>(f: F) -> Result<Self> {
+            let mut previous = std::mem::MaybeUninit::<libc::termios>::uninit();
+
+            if unsafe { libc::tcgetattr(libc::STDIN_FILENO, previous.as_mut_ptr()) } != 0 {
+                return Err(Error::last_os_error());
+            }
+
+            let previous = unsafe { previous.assume_init() };
+            let mut current = previous;
+            f(&mut current);
+
+            if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &current) } != 0 {
+                return Err(Error::last_os_error());
+            }
+
+            Ok(Self(previous))
+        }
+
+        /// Restore previous terminal attributes.
+        pub(super) fn restore(&self) -> Result<()> {
+            if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.0) } != 0 {
+                return Err(Error::last_os_error());
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Drop for Termios {
+        fn drop(&mut self) {
+            let _ = self.restore();
+        }
+    }
+
+    impl std::fmt::Debug for Termios {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Termios").finish_non_exhaustive()
+        }
+    }
+}
@@ -1,9 +1,34 @@
 //! Terminal related utilities.
 
 mod colours;
+#[cfg(feature = "std")]
 mod env;
+#[cfg(feature = "std")]
+mod input;
+#[cfg(feature = "std")]
+mod region;
+#[cfg(feature = "std")]
+mod renderer;
+#[cfg(feature = "sysinfo")]
+mod system;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "std")]
 mod writer;
 
 pub use colours::*;
+#[cfg(feature = "std")]
 pub use env::*;
+#[cfg(feature = "std")]
+pub use input::*;
+#[cfg(feature = "std")]
+pub use region::*;
+#[cfg(feature = "std")]
+pub use renderer::*;
+#[cfg(feature = "sysinfo")]
+pub(crate) use system::*;
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+pub use wasm::*;
+#[cfg(feature = "std")]
 pub use writer::*;
@@ -0,0 +1,83 @@
+use crate::progress::Bar;
+use crate::term::{Renderer, Writer};
+
+/// Pins progress bars at bottom of terminal using a scroll region (`DECSTBM`).
+///
+/// Bottom `nrows` rows of terminal are reserved for progress bars, while normal program output
+/// (e.g. `println!`) scrolls above them. So there is no need to route every print through [BarExt::write](crate::BarExt::write).
+/// Progress bars should be rendered using [RegionRenderer](crate::term::RegionRenderer), where position of bar is its row in reserved area.
+/// Scroll region is reset when [ScrollRegion](crate::term::ScrollRegion) is dropped.
+///
+/// Terminal resizes are not tracked, so a new region should be created after a resize.
+///
+/// # Example
+///
+/// ```no_run
+/// use kdam::{term::ScrollRegion, tqdm, BarExt};
+///
+/// let region = ScrollRegion::new(1);
+/// let mut pb = tqdm!(total = 100, renderer = region.renderer());
+///
+/// for i in 0..100 {
+///     println!("processed item {}", i);
+///     pb.update(1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ScrollRegion {
+    rows: u16,
+    top: u16,
+    writer: Writer,
+}
+
+impl ScrollRegion {
+    /// Reserve bottom `nrows` rows of terminal for progress bars.
+    pub fn new(nrows: u16) -> Self {
+        let rows = crate::term::get_rows_or(24);
+        let nrows = nrows.clamp(1, rows.saturating_sub(1).max(1));
+        let top = rows - nrows + 1;
+        let writer = Writer::Stderr;
+
+        writer.print(format_args!(
+            "{}\x1b[{}A\x1b7\x1b[1;{}r\x1b8",
+            "\n".repeat(nrows as usize),
+            nrows,
+            top - 1
+        ));
+
+        Self { rows, top, writer }
+    }
+
+    /// Returns renderer which renders bars in reserved rows of this region.
+    pub fn renderer(&self) -> RegionRenderer {
+        RegionRenderer { top: self.top }
+    }
+}
+
+impl Drop for ScrollRegion {
+    fn drop(&mut self) {
+        let _ = self
+            .writer
+            .try_print(format_args!("\x1b7\x1b[r\x1b8\x1b[{};1H\n", self.rows));
+    }
+}
+
+/// Renders bar in reserved rows of a [ScrollRegion](crate::term::ScrollRegion), at row given by position of bar.
+#[derive(Debug, Clone)]
+pub struct RegionRenderer {
+    top: u16,
+}
+
+impl Renderer for RegionRenderer {
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()> {
+        pb.get_writer().try_print(format_args!(
+            "\x1b7\x1b[{};1H\r{}\x1b[K\x1b8",
+            self.top + pb.get_position(),
+            text
+        ))
+    }
+
+    fn clear(&self, pb: &Bar) -> std::io::Result<()> {
+        self.render(pb, "")
+    }
+}
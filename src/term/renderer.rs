@@ -0,0 +1,403 @@
+use crate::progress::Bar;
+use crate::term::Colorizer;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/// Backend which displays rendered text of [Bar](crate::Bar).
+///
+/// Bar logic (when to refresh and what to display) stays same for every backend,
+/// only the way of displaying rendered text differs.
+/// Implement this trait for creating new backends like GUI or web.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::Renderer, tqdm, Bar, BarExt};
+///
+/// #[derive(Debug)]
+/// struct Percentage;
+///
+/// impl Renderer for Percentage {
+///     fn render(&self, pb: &Bar, _text: &str) -> std::io::Result<()> {
+///         eprintln!("{:.0}%", pb.percentage() * 100.0);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut pb = tqdm!(total = 10, renderer = Percentage);
+///
+/// for _ in 0..10 {
+///     pb.update(1);
+/// }
+/// ```
+pub trait Renderer: std::fmt::Debug + Send + Sync {
+    /// Display rendered `text` of bar.
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()>;
+
+    /// Clear displayed text of bar (default: does nothing).
+    fn clear(&self, _pb: &Bar) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Renders bar in terminal at its position using carriage returns and ANSI escape sequences.
+/// This is the default renderer.
+///
+/// Lines of text after first one are rendered in rows reserved below bar, if bar has [extra_lines](crate::BarBuilder::extra_lines).
+#[derive(Debug, Clone, Default)]
+pub struct AnsiRenderer;
+
+impl AnsiRenderer {
+    /// Render `rows` in extra lines of bar, extra lines without any row are cleared.
+    fn render_extra_lines(pb: &Bar, rows: &str) -> std::io::Result<()> {
+        let mut rows = rows.split('\n');
+
+        for i in 1..=pb.get_extra_lines() {
+            pb.get_writer().try_print_at(
+                pb.get_position() + i,
+                &format!("\r{}\x1b[K", rows.next().unwrap_or_default()),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Renderer for AnsiRenderer {
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()> {
+        let position = pb.get_position();
+        let (text, rows) = match text.split_once('\n') {
+            Some(x) if pb.get_extra_lines() > 0 => x,
+            _ => (text, ""),
+        };
+
+        if position == 0 {
+            pb.get_writer()
+                .try_print(format_args!("{}\r{}", pb.fmt_taskbar_progress(), text))?;
+        } else {
+            pb.get_writer().try_print_at(position, text)?;
+        }
+
+        Self::render_extra_lines(pb, rows)
+    }
+
+    fn clear(&self, pb: &Bar) -> std::io::Result<()> {
+        self.render(
+            pb,
+            &format!(
+                "\r{}",
+                " ".repeat(crate::term::get_columns_or(pb.get_bar_length() as u16) as usize)
+            ),
+        )
+    }
+}
+
+/// Renders bar like [AnsiRenderer](crate::term::AnsiRenderer), but only changed cells are written when possible.
+///
+/// Each frame is compared against previous frame and only the changed span is written after moving cursor to it,
+/// which reduces bandwidth and flicker over slow links (e.g. SSH). Frames containing escape sequences or
+/// wide characters are always written fully.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::DiffRenderer, tqdm, BarExt};
+///
+/// let mut pb = tqdm!(total = 100, renderer = DiffRenderer::default());
+///
+/// for _ in 0..100 {
+///     pb.update(1);
+/// }
+///
+/// eprint!("\n");
+/// ```
+#[derive(Debug, Default)]
+pub struct DiffRenderer {
+    previous: Mutex<Option<(u16, Vec<char>)>>,
+}
+
+impl DiffRenderer {
+    /// Forget previous frame, so that next frame is written fully.
+    /// Useful when terminal is modified by something other than this renderer.
+    pub fn reset(&self) {
+        *self.previous.lock().unwrap() = None;
+    }
+
+    /// Returns wheter every character of frame occupies a single cell or not.
+    fn diffable(frame: &[char]) -> bool {
+        frame.iter().all(|x| {
+            !x.is_control()
+                && (*x < '\u{1100}'
+                    || ('\u{2000}'..='\u{2BFF}').contains(x)
+                    || ('\u{E000}'..='\u{F8FF}').contains(x))
+        })
+    }
+
+    /// Returns changed span of `current` frame prefixed with cursor movement.
+    fn diff(previous: &[char], current: &[char]) -> String {
+        let first = match previous.iter().zip(current).position(|(x, y)| x != y) {
+            Some(first) => first,
+            None if previous.len() == current.len() => return "".to_owned(),
+            None => previous.len().min(current.len()),
+        };
+
+        let span = if previous.len() == current.len() {
+            let last = current.len()
+                - previous
+                    .iter()
+                    .rev()
+                    .zip(current.iter().rev())
+                    .position(|(x, y)| x != y)
+                    .unwrap_or(0);
+            current[first..last].iter().collect::<String>()
+        } else if current.len() < previous.len() {
+            current[first..].iter().collect::<String>() + "\x1b[K"
+        } else {
+            current[first..].iter().collect::<String>()
+        };
+
+        format!("\x1b[{}G{}", first + 1, span)
+    }
+}
+
+impl Renderer for DiffRenderer {
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()> {
+        let position = pb.get_position();
+        let current = text.chars().collect::<Vec<char>>();
+        let mut previous = self.previous.lock().unwrap();
+
+        let update = match previous.as_ref() {
+            Some((previous_position, previous))
+                if *previous_position == position
+                    && Self::diffable(previous)
+                    && Self::diffable(&current) =>
+            {
+                Some(Self::diff(previous, &current))
+            }
+            _ => None,
+        };
+
+        *previous = Some((position, current));
+
+        match update {
+            Some(update) if update.is_empty() => Ok(()),
+            Some(update) if position == 0 => {
+                pb.get_writer()
+                    .try_print(format_args!("{}{}", pb.fmt_taskbar_progress(), update))
+            }
+            Some(update) => pb.get_writer().try_print_at(position, &update),
+            None => AnsiRenderer.render(pb, text),
+        }
+    }
+
+    fn clear(&self, pb: &Bar) -> std::io::Result<()> {
+        self.render(
+            pb,
+            &" ".repeat(crate::term::get_columns_or(pb.get_bar_length() as u16) as usize),
+        )?;
+        AnsiRenderer::render_extra_lines(pb, "")
+    }
+}
+
+/// Renders bar as plain text lines without any escape sequences, useful for log files and CI.
+#[derive(Debug, Clone, Default)]
+pub struct PlainRenderer;
+
+impl Renderer for PlainRenderer {
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()> {
+        pb.get_writer()
+            .try_print(format_args!("{}\n", text.trim_ansi()))
+    }
+}
+
+/// Renders bar state as JSON lines, like `{"desc":"","counter":5,"total":10,"elapsed":0.5,"rate":10}`.
+/// Rendered text is ignored, so this renderer is well suited for machine consumption.
+/// Rate is `null` until it can be computed.
+#[derive(Debug, Clone, Default)]
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, pb: &Bar, _text: &str) -> std::io::Result<()> {
+        let desc = pb
+            .get_desc()
+            .chars()
+            .map(|x| match x {
+                '"' => "\\\"".to_owned(),
+                '\\' => "\\\\".to_owned(),
+                x if x.is_control() => format!("\\u{:04x}", x as u32),
+                x => x.to_string(),
+            })
+            .collect::<String>();
+
+        let rate = pb.rate();
+
+        pb.get_writer().try_print(format_args!(
+            "{{\"desc\":\"{}\",\"counter\":{},\"total\":{},\"elapsed\":{},\"rate\":{}}}\n",
+            desc,
+            pb.get_counter(),
+            pb.get_total(),
+            pb.elapsed_time,
+            if rate.is_finite() {
+                rate.to_string()
+            } else {
+                "null".to_owned()
+            }
+        ))
+    }
+}
+
+/// Renders bar as machine readable numbers, one per line, compatible with `pv --numeric` output.
+/// Integer percentage is written by default, counter is written in counter mode or if total is unknown.
+/// Numbers are written to bar writer, or to a custom writer (e.g. a file or a pipe).
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::NumericRenderer, tqdm, BarExt};
+///
+/// let mut pb = tqdm!(total = 10, renderer = NumericRenderer::default());
+///
+/// for _ in 0..10 {
+///     pb.update(1);
+/// }
+///
+/// let path = std::env::temp_dir().join("kdam-progress.txt");
+/// let file = std::fs::File::create(&path).unwrap();
+/// let mut pb = tqdm!(renderer = NumericRenderer::new(file).with_counter());
+/// pb.update(5);
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Default)]
+pub struct NumericRenderer {
+    counter: bool,
+    writer: Option<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl NumericRenderer {
+    /// Create a new renderer which writes numbers to `writer`.
+    pub fn new<W: Write + Send + 'static>(writer: W) -> Self {
+        Self {
+            counter: false,
+            writer: Some(Mutex::new(Box::new(writer))),
+        }
+    }
+
+    /// Write counter instead of percentage, like `pv --numeric --bytes`.
+    pub fn with_counter(mut self) -> Self {
+        self.counter = true;
+        self
+    }
+}
+
+impl std::fmt::Debug for NumericRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NumericRenderer")
+            .field("counter", &self.counter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Renderer for NumericRenderer {
+    fn render(&self, pb: &Bar, _text: &str) -> std::io::Result<()> {
+        let value = if self.counter || pb.indefinite() {
+            pb.get_counter()
+        } else {
+            (pb.clamped_percentage() * 100.0) as usize
+        };
+
+        match &self.writer {
+            Some(writer) => {
+                let mut writer = writer.lock().unwrap();
+                writeln!(writer, "{}", value)?;
+                writer.flush()
+            }
+            None => pb.get_writer().try_print(format_args!("{}\n", value)),
+        }
+    }
+}
+
+/// Passes rendered text of bar to a callback, useful for GUI and web frontends (e.g. updating a DOM element).
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::CallbackRenderer, tqdm, BarExt};
+///
+/// let mut pb = tqdm!(
+///     total = 100,
+///     renderer = CallbackRenderer::new(|pb, _text| {
+///         println!("{}/{}", pb.get_counter(), pb.get_total());
+///     })
+/// );
+/// ```
+pub struct CallbackRenderer {
+    callback: Callback,
+}
+
+type Callback = Box<dyn Fn(&Bar, &str) + Send + Sync>;
+
+impl CallbackRenderer {
+    /// Create a new renderer which calls `callback` with bar and its rendered text.
+    pub fn new<F: Fn(&Bar, &str) + Send + Sync + 'static>(callback: F) -> Self {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+}
+
+impl std::fmt::Debug for CallbackRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallbackRenderer").finish_non_exhaustive()
+    }
+}
+
+impl Renderer for CallbackRenderer {
+    fn render(&self, pb: &Bar, text: &str) -> std::io::Result<()> {
+        (self.callback)(pb, text);
+        Ok(())
+    }
+}
+
+/// Captures rendered text of bar in memory, useful for testing.
+/// Captured frames are shared between clones of renderer.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::CaptureRenderer, tqdm, BarExt};
+///
+/// let capture = CaptureRenderer::default();
+/// let mut pb = tqdm!(total = 10, renderer = capture.clone());
+/// pb.refresh();
+///
+/// assert_eq!(capture.frames().len(), 1);
+/// assert!(capture.frames()[0].starts_with("  0%"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CaptureRenderer {
+    frames: Arc<Mutex<Vec<String>>>,
+}
+
+impl CaptureRenderer {
+    /// Returns all captured frames.
+    pub fn frames(&self) -> Vec<String> {
+        self.frames.lock().unwrap().clone()
+    }
+
+    /// Returns last captured frame.
+    pub fn last(&self) -> Option<String> {
+        self.frames.lock().unwrap().last().cloned()
+    }
+
+    /// Remove all captured frames.
+    pub fn clear_frames(&self) {
+        self.frames.lock().unwrap().clear();
+    }
+}
+
+impl Renderer for CaptureRenderer {
+    fn render(&self, _pb: &Bar, text: &str) -> std::io::Result<()> {
+        self.frames.lock().unwrap().push(text.to_owned());
+        Ok(())
+    }
+}
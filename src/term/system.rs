@@ -0,0 +1,59 @@
+use std::sync::Mutex;
+use std::time::Instant;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+struct ProcessUsage {
+    cpu: f32,
+    memory: u64,
+    pid: Pid,
+    system: System,
+    updated: Option<Instant>,
+}
+
+static USAGE: Mutex<Option<ProcessUsage>> = Mutex::new(None);
+
+/// Returns CPU usage (in percentage) and resident memory (in bytes) of current process.
+/// Values are refreshed at most once per [MINIMUM_CPU_UPDATE_INTERVAL], since CPU usage is computed between two refreshes.
+pub(crate) fn process_usage() -> (f32, u64) {
+    let mut usage = match USAGE.lock() {
+        Ok(usage) => usage,
+        Err(_) => return (0.0, 0),
+    };
+
+    if usage.is_none() {
+        let pid = match sysinfo::get_current_pid() {
+            Ok(pid) => pid,
+            Err(_) => return (0.0, 0),
+        };
+
+        *usage = Some(ProcessUsage {
+            cpu: 0.0,
+            memory: 0,
+            pid,
+            system: System::new(),
+            updated: None,
+        });
+    }
+
+    let usage = usage.as_mut().unwrap();
+
+    if usage
+        .updated
+        .is_none_or(|x| x.elapsed() >= MINIMUM_CPU_UPDATE_INTERVAL)
+    {
+        usage.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[usage.pid]),
+            true,
+            ProcessRefreshKind::nothing().with_cpu().with_memory(),
+        );
+
+        if let Some(process) = usage.system.process(usage.pid) {
+            usage.cpu = process.cpu_usage();
+            usage.memory = process.memory();
+        }
+
+        usage.updated = Some(Instant::now());
+    }
+
+    (usage.cpu, usage.memory)
+}
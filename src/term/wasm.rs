@@ -0,0 +1,27 @@
+use crate::progress::Bar;
+use crate::term::{Colorizer, Renderer};
+
+/// Renders bar in browser console using `console.log`, with ANSI escape sequences removed.
+/// Use [CallbackRenderer](crate::term::CallbackRenderer) for updating DOM elements instead.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{term::ConsoleRenderer, tqdm, BarExt};
+///
+/// let mut pb = tqdm!(total = 100, renderer = ConsoleRenderer);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConsoleRenderer;
+
+impl Renderer for ConsoleRenderer {
+    fn render(&self, _pb: &Bar, text: &str) -> std::io::Result<()> {
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::log_1(&text.trim_ansi().into());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        eprintln!("{}", text.trim_ansi());
+
+        Ok(())
+    }
+}
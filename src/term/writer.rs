@@ -1,57 +1,77 @@
-use std::io::Write;
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicU16, Ordering};
 
 /// Stderr and Stdout writer for [Bar](crate::Bar).
 #[derive(Debug, Clone)]
 pub enum Writer {
     Stderr,
     Stdout,
+    /// Discard all output. Bars using this writer still track progress,
+    /// but skip rendering, terminal size queries and output locking entirely, which is useful when running headless.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{term::Writer, tqdm, BarExt};
+    ///
+    /// let mut pb = tqdm!(total = 100, writer = Writer::Null);
+    ///
+    /// assert!(!pb.update(100));
+    /// assert_eq!(pb.get_counter(), 100);
+    /// ```
+    Null,
 }
 
 impl From<&str> for Writer {
     fn from(output: &str) -> Self {
         match output.to_lowercase().as_str() {
             "stdout" => Self::Stdout,
+            "null" => Self::Null,
             _ => Self::Stderr,
         }
     }
 }
 
 impl Writer {
+    /// Returns wheter writer discards all output or not.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns wheter writer is connected to a terminal or not.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            Self::Stderr => std::io::stderr().is_terminal(),
+            Self::Stdout => std::io::stdout().is_terminal(),
+            Self::Null => false,
+        }
+    }
+
     /// Print [Arguments](std::fmt::Arguments) in terminal followed by a flush.
     pub fn print(&self, args: std::fmt::Arguments) {
+        self.try_print(args).unwrap();
+    }
+
+    /// Print [Arguments](std::fmt::Arguments) in terminal followed by a flush.
+    /// Unlike `self.print`, io errors are returned instead of panicking.
+    pub fn try_print(&self, args: std::fmt::Arguments) -> std::io::Result<()> {
         match self {
-            Self::Stderr => {
-                let mut writer = std::io::stderr();
-                writer.write_fmt(args).unwrap();
-                writer.flush().unwrap();
-            }
-            Self::Stdout => {
-                let mut writer = std::io::stdout();
-                writer.write_fmt(args).unwrap();
-                writer.flush().unwrap();
-            }
+            Self::Stderr => Lines::new(std::io::stderr()).write_fmt(args),
+            Self::Stdout => Lines::new(std::io::stdout()).write_fmt(args),
+            Self::Null => Ok(()),
         }
     }
 
     /// Print `&str` in terminal followed by a flush.
     pub fn print_str(&self, text: &str) {
-        match self {
-            Self::Stderr => {
-                let mut writer = std::io::stderr();
-                writer.write_all(text.as_bytes()).unwrap();
-                writer.flush().unwrap();
-            }
-            Self::Stdout => {
-                let mut writer = std::io::stdout();
-                writer.write_all(text.as_bytes()).unwrap();
-                writer.flush().unwrap();
-            }
-        }
+        self.try_print(format_args!("{}", text)).unwrap();
     }
 
     /// Prints to the standard error at specified position.
     ///
     /// Also cursor position is restored to original position after print.
+    /// Newlines are only printed for rows below cursor which are not reserved yet, already
+    /// reserved rows are skipped by moving cursor down, so terminal doesn't scroll and positioned text doesn't jump.
     ///
     /// # Example
     ///
@@ -61,49 +81,80 @@ impl Writer {
     /// Writer::Stderr.print_at(1, format!("1 + 1 = {}", 2));
     /// ```
     pub fn print_at<T: Into<String>>(&self, position: usize, text: T) {
-        match self {
-            Self::Stderr => {
-                let mut writer = std::io::stderr();
-
-                crate::thread::lock::acquire();
-
-                if position > 0 {
-                    writer
-                        .write_fmt(format_args!(
-                            "{}{}\x1b[{}A",
-                            "\n".repeat(position),
-                            text.into(),
-                            position
-                        ))
-                        .unwrap();
-                } else {
-                    writer.write_fmt(format_args!("{}", text.into())).unwrap();
-                }
-
-                writer.flush().unwrap();
-                crate::thread::lock::release();
-            }
-            Self::Stdout => {
-                let mut writer = std::io::stdout();
-
-                crate::thread::lock::acquire();
-
-                if position > 0 {
-                    writer
-                        .write_fmt(format_args!(
-                            "{}{}\x1b[{}A",
-                            "\n".repeat(position),
-                            text.into(),
-                            position
-                        ))
-                        .unwrap();
-                } else {
-                    writer.write_fmt(format_args!("{}", text.into())).unwrap();
-                }
-
-                writer.flush().unwrap();
-                crate::thread::lock::release();
-            }
+        crate::thread::lock::acquire();
+        let result = self.try_print_at(position as u16, &text.into());
+        crate::thread::lock::release();
+        result.unwrap();
+    }
+
+    /// Print `text` at `position` rows below cursor and move cursor back, see [print_at](Self::print_at).
+    pub(crate) fn try_print_at(&self, position: u16, text: &str) -> std::io::Result<()> {
+        if position == 0 {
+            return self.try_print(format_args!("{}", text));
         }
+
+        let reserved = RESERVED_ROWS.load(Ordering::Relaxed);
+
+        if reserved < position {
+            let down = if reserved == 0 {
+                String::new()
+            } else {
+                format!("\x1b[{}B", reserved)
+            };
+
+            self.try_print(format_args!(
+                "{}{}{}\x1b[{}A",
+                down,
+                "\n".repeat((position - reserved) as usize),
+                text,
+                position
+            ))?;
+            RESERVED_ROWS.store(position, Ordering::Relaxed);
+            Ok(())
+        } else {
+            self.try_print(format_args!(
+                "\x1b[{}B\r{}\x1b[{}A",
+                position, text, position
+            ))
+        }
+    }
+}
+
+/// Number of rows below cursor which are reserved by positioned prints.
+/// Newlines printed using [Writer](crate::term::Writer) move cursor down, so they release reserved rows.
+static RESERVED_ROWS: AtomicU16 = AtomicU16::new(0);
+
+/// Writer which releases reserved rows for every newline written, followed by a flush.
+struct Lines<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> Lines<W> {
+    fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    fn write_fmt(mut self, args: std::fmt::Arguments) -> std::io::Result<()> {
+        Write::write_fmt(&mut self, args)?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Write for Lines<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        let lines = buf[..n].iter().filter(|x| **x == b'\n').count() as u16;
+
+        if lines != 0 {
+            let _ = RESERVED_ROWS.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| {
+                Some(x.saturating_sub(lines))
+            });
+        }
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
     }
 }
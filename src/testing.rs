@@ -0,0 +1,87 @@
+//! Capture rendered frames of progress bars for golden file (snapshot) testing of styles.
+//!
+//! Every frame displayed by a bar is recorded instead of being written to terminal,
+//! so rendering behaviour can be locked down by comparing captured frames against expected ones.
+//! Frames are returned without colours by [capture] and [capture_with], or with ANSI escape
+//! sequences by [capture_ansi].
+//!
+//! # Example
+//!
+//! ```
+//! use kdam::{BarExt, MockClock};
+//! use std::time::Duration;
+//!
+//! let clock = MockClock::default();
+//! let frames = kdam::testing::capture(|pb| {
+//!     pb.set_total(4);
+//!     pb.set_clock(clock.clone());
+//!
+//!     for _ in 0..4 {
+//!         clock.advance(Duration::from_secs(1));
+//!         pb.update(1);
+//!     }
+//! });
+//!
+//! assert_eq!(frames.len(), 4);
+//! assert_eq!(frames[1], " 50%|████████████████████▎                   | 2/4 [00:02<00:02, 1.00it/s]");
+//! ```
+
+use crate::progress::{Bar, MockClock};
+use crate::term::{CaptureRenderer, Colorizer};
+
+/// Run `f` with a bar, which is 40 columns wide, refreshed on every update and whose clock is stopped
+/// (see [MockClock](crate::MockClock)), and return all rendered frames without colours.
+///
+/// Clock of bar can be replaced inside `f` using [set_clock](crate::Bar::set_clock) to control elapsed time.
+pub fn capture<F: FnOnce(&mut Bar)>(f: F) -> Vec<String> {
+    let pb = Bar::builder()
+        .ncols(40_i16)
+        .mininterval(0.0)
+        .clock(MockClock::default())
+        .build()
+        .unwrap();
+
+    capture_with(pb, f)
+}
+
+/// Run `f` with `pb` and return all rendered frames without colours.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+///
+/// let pb = tqdm!(total = 2, desc = "styled", colour = "green", ncols = 20_i16, mininterval = 0.0);
+/// let frames = kdam::testing::capture_with(pb, |pb| {
+///     pb.update(2);
+/// });
+///
+/// assert!(frames[0].starts_with("styled: 100%|████████████████████| 2/2"));
+/// ```
+pub fn capture_with<F: FnOnce(&mut Bar)>(pb: Bar, f: F) -> Vec<String> {
+    capture_ansi(pb, f)
+        .into_iter()
+        .map(|frame| frame.trim_ansi())
+        .collect()
+}
+
+/// Run `f` with `pb` and return all rendered frames including colours and other ANSI escape sequences.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+///
+/// let pb = tqdm!(total = 2, colour = "green", mininterval = 0.0);
+/// let frames = kdam::testing::capture_ansi(pb, |pb| {
+///     pb.update(2);
+/// });
+///
+/// assert!(frames[0].contains("\x1b["));
+/// ```
+pub fn capture_ansi<F: FnOnce(&mut Bar)>(mut pb: Bar, f: F) -> Vec<String> {
+    let capture = CaptureRenderer::default();
+    pb.set_renderer(capture.clone());
+    f(&mut pb);
+    capture.frames()
+}
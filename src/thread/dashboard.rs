@@ -0,0 +1,280 @@
+use crate::progress::{Bar, BarExt};
+use crate::term::{Colorizer, Writer};
+use std::sync::{Arc, Mutex, Weak};
+
+/// Shared bar along with its `(rate, eta)`.
+type Row = (Arc<Mutex<Bar>>, (f32, f32));
+
+/// Order of bars listed in a [Dashboard](crate::Dashboard).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashboardSort {
+    /// Bars are listed in order of registration.
+    #[default]
+    Registration,
+    /// Bars which will finish soonest are listed first, completed bars are listed at end.
+    Eta,
+    /// Fastest bars are listed first.
+    Rate,
+}
+
+/// Full screen dashboard which lists all bars of global [registry](crate::registry),
+/// useful for tools which run hundreds of concurrent transfers.
+///
+/// Dashboard is drawn in alternate screen buffer of terminal, which is restored when dashboard is dropped.
+/// Bars can be sorted by ETA or rate, a totals row is drawn at bottom and list can be scrolled
+/// when there are more bars than terminal rows. Keys read using [poll_key](crate::term::poll_key) are handled using [handle_key](Self::handle_key).
+///
+/// Bars drawn by dashboard are disabled, so that they don't draw themselves over dashboard.
+/// Their previous disable state is restored when dashboard is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use kdam::{term::RawMode, tqdm, BarExt, Dashboard, DashboardSort};
+/// use std::time::Duration;
+///
+/// for i in 0..200 {
+///     kdam::registry::get_or_create_with(&format!("file{}", i), || tqdm!(total = 100));
+/// }
+///
+/// let _raw = RawMode::enter();
+/// let mut dashboard = Dashboard::new();
+/// dashboard.set_sort(DashboardSort::Eta);
+///
+/// for _ in 0..100 {
+///     for name in kdam::registry::list() {
+///         kdam::registry::get(&name).unwrap().lock().unwrap().update(1);
+///     }
+///
+///     if let Some(key) = kdam::term::poll_key(Duration::from_millis(50)) {
+///         dashboard.handle_key(key);
+///     }
+///
+///     dashboard.draw();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Dashboard {
+    disabled: Vec<(Weak<Mutex<Bar>>, bool)>,
+    offset: usize,
+    sort: DashboardSort,
+    writer: Writer,
+}
+
+impl Default for Dashboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dashboard {
+    /// Switch terminal to alternate screen buffer and create a new dashboard.
+    pub fn new() -> Self {
+        let writer = Writer::Stderr;
+        writer.print_str("\x1b[?1049h\x1b[?25l");
+
+        Self {
+            disabled: vec![],
+            offset: 0,
+            sort: DashboardSort::Registration,
+            writer,
+        }
+    }
+
+    /// Returns order of listed bars.
+    pub fn get_sort(&self) -> DashboardSort {
+        self.sort
+    }
+
+    /// Set order of listed bars.
+    pub fn set_sort(&mut self, sort: DashboardSort) {
+        self.sort = sort;
+    }
+
+    /// Scroll list of bars by `rows`, negative values scroll up.
+    pub fn scroll(&mut self, rows: isize) {
+        self.offset = self.offset.saturating_add_signed(rows);
+    }
+
+    /// Handle a key press, returns wheter key is handled or not.
+    ///
+    /// - `j` / `k`: scroll down / up by one row.
+    /// - `J` / `K`: scroll down / up by one page.
+    /// - `e`, `r`, `n`: sort by ETA, rate or order of registration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use kdam::{Dashboard, DashboardSort};
+    ///
+    /// let mut dashboard = Dashboard::new();
+    ///
+    /// assert!(dashboard.handle_key('r'));
+    /// assert_eq!(dashboard.get_sort(), DashboardSort::Rate);
+    /// assert!(!dashboard.handle_key('x'));
+    /// ```
+    pub fn handle_key(&mut self, key: char) -> bool {
+        let page = Self::nrows() as isize;
+
+        match key {
+            'j' => self.scroll(1),
+            'k' => self.scroll(-1),
+            'J' => self.scroll(page),
+            'K' => self.scroll(-page),
+            'e' => self.sort = DashboardSort::Eta,
+            'r' => self.sort = DashboardSort::Rate,
+            'n' => self.sort = DashboardSort::Registration,
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Returns dashboard text, which consists of a header row, visible bars and a totals row.
+    pub fn render(&mut self) -> String {
+        let mut bars = crate::registry::list()
+            .iter()
+            .filter_map(|name| crate::registry::get(name))
+            .map(|pb| {
+                let stats = {
+                    let mut pb_dashboard = pb.lock().unwrap_or_else(|x| x.into_inner());
+
+                    if !self
+                        .disabled
+                        .iter()
+                        .any(|(x, _)| x.as_ptr() == Arc::as_ptr(&pb))
+                    {
+                        self.disabled
+                            .push((Arc::downgrade(&pb), pb_dashboard.get_disable()));
+                    }
+
+                    pb_dashboard.set_disable(true);
+                    let rate = if pb_dashboard.elapsed_time() > 0.0 {
+                        pb_dashboard.rate()
+                    } else {
+                        0.0
+                    };
+                    let eta = if pb_dashboard.completed() {
+                        f32::INFINITY
+                    } else {
+                        pb_dashboard.remaining_time()
+                    };
+                    (rate, eta)
+                };
+                (pb, stats)
+            })
+            .collect::<Vec<Row>>();
+
+        match self.sort {
+            DashboardSort::Registration => {}
+            DashboardSort::Eta => bars.sort_by(|a, b| a.1 .1.total_cmp(&b.1 .1)),
+            DashboardSort::Rate => bars.sort_by(|a, b| b.1 .0.total_cmp(&a.1 .0)),
+        }
+
+        let nrows = Self::nrows();
+        self.offset = self.offset.min(bars.len().saturating_sub(nrows));
+
+        let mut lines = vec![format!(
+            "{} bars, sorted by {} (j/k: scroll, e/r/n: sort by eta/rate/registration)",
+            bars.len(),
+            match self.sort {
+                DashboardSort::Registration => "registration",
+                DashboardSort::Eta => "eta",
+                DashboardSort::Rate => "rate",
+            }
+        )
+        .colorize("bold")];
+
+        for (pb, _) in bars.iter().skip(self.offset).take(nrows) {
+            lines.push(pb.lock().unwrap_or_else(|x| x.into_inner()).render());
+        }
+
+        for _ in lines.len()..=nrows {
+            lines.push(String::new());
+        }
+
+        lines.push(self.fmt_totals(&bars).colorize("bold"));
+        lines.join("\n")
+    }
+
+    /// Draw dashboard over previous frame.
+    pub fn draw(&mut self) {
+        let text = self.render();
+        let text = text.replace('\n', "\x1b[K\n");
+        self.writer
+            .print(format_args!("\x1b[H{}\x1b[K\x1b[J", text));
+    }
+
+    /// Returns totals row like `total: 2/6 tasks | 47% | 120.00it/s | ETA 00:41`.
+    /// Rate is only summed if all bars have same unit, otherwise it is left out.
+    fn fmt_totals(&self, bars: &[Row]) -> String {
+        let (mut counter, mut total, mut completed) = (0, 0, 0);
+        let (mut rate, mut eta) = (0.0, 0.0_f32);
+        let mut unit = None;
+        let mut same_unit = true;
+
+        for (pb, (bar_rate, bar_eta)) in bars {
+            let pb = pb.lock().unwrap_or_else(|x| x.into_inner());
+            let bar_unit = (pb.get_unit().to_owned(), pb.decimals);
+
+            match &unit {
+                Some(unit) => same_unit &= *unit == bar_unit,
+                None => unit = Some(bar_unit),
+            }
+
+            counter += pb.get_counter().min(pb.get_total());
+            total += pb.get_total();
+            rate += bar_rate;
+
+            if pb.completed() {
+                completed += 1;
+            } else if bar_eta.is_finite() {
+                eta = eta.max(*bar_eta);
+            }
+        }
+
+        let percentage = if total == 0 {
+            100.0
+        } else {
+            (counter as f32 / total as f32 * 100.0).floor()
+        };
+
+        let rate = match bars.first() {
+            Some((pb, _)) if same_unit => format!(
+                " | {}",
+                pb.lock()
+                    .unwrap_or_else(|x| x.into_inner())
+                    .fmt_rate_value(rate)
+            ),
+            _ => String::new(),
+        };
+
+        format!(
+            "total: {}/{} tasks | {:.0}%{} | ETA {}",
+            completed,
+            bars.len(),
+            percentage,
+            rate,
+            crate::format::format_eta(eta, false)
+        )
+    }
+
+    /// Returns number of rows available for bars.
+    fn nrows() -> usize {
+        crate::term::get_rows_or(24).saturating_sub(2).max(1) as usize
+    }
+}
+
+impl Drop for Dashboard {
+    fn drop(&mut self) {
+        for (pb, disable) in &self.disabled {
+            if let Some(pb) = pb.upgrade() {
+                pb.lock()
+                    .unwrap_or_else(|x| x.into_inner())
+                    .set_disable(*disable);
+            }
+        }
+
+        let _ = self.writer.try_print(format_args!("\x1b[?25h\x1b[?1049l"));
+    }
+}
@@ -0,0 +1,87 @@
+//! Ctrl+C aware progress bars.
+//!
+//! Once [install](crate::interrupt::install) is called, `SIGINT` is handled in a separate thread which
+//! finalizes all registered progress bars (bars with `leave=true` are refreshed and kept, others are cleared),
+//! restores cursor and then optionally re-raises `SIGINT` for terminating process.
+//! So interrupted programs don't leave corrupted terminal lines.
+//!
+//! ```no_run
+//! use kdam::{tqdm, BarExt};
+//!
+//! kdam::interrupt::install(true).unwrap();
+//! let pb = kdam::interrupt::register(tqdm!(total = 100));
+//!
+//! for _ in 0..100 {
+//!     pb.lock().unwrap().update(1);
+//!     std::thread::sleep(std::time::Duration::from_millis(100));
+//! }
+//!
+//! eprint!("\n");
+//! ```
+
+use crate::progress::{Bar, BarExt};
+use crate::term::Writer;
+use signal_hook::consts::SIGINT;
+use signal_hook::iterator::Signals;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static REGISTRY: Mutex<Vec<Weak<Mutex<Bar>>>> = Mutex::new(vec![]);
+
+/// Install `SIGINT` handler.
+/// If `reraise` is true, process is terminated by `SIGINT` after finalizing bars,
+/// otherwise process continues and [is_interrupted](crate::interrupt::is_interrupted) returns `true`.
+pub fn install(reraise: bool) -> std::io::Result<()> {
+    let mut signals = Signals::new([SIGINT])?;
+
+    thread::spawn(move || {
+        for signal in signals.forever() {
+            INTERRUPTED.store(true, Ordering::SeqCst);
+            finalize();
+
+            if reraise {
+                let _ = signal_hook::low_level::emulate_default_handler(signal);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Register a progress bar which is finalized on `SIGINT`.
+/// Bar is unregistered when returned [Arc](std::sync::Arc) is dropped.
+pub fn register(pb: Bar) -> Arc<Mutex<Bar>> {
+    let pb_arc = Arc::new(Mutex::new(pb));
+    let mut registry = REGISTRY.lock().unwrap_or_else(|x| x.into_inner());
+    registry.retain(|x| x.strong_count() > 0);
+    registry.push(Arc::downgrade(&pb_arc));
+    pb_arc
+}
+
+/// Returns wheter `SIGINT` was received or not.
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::Acquire)
+}
+
+/// Finalize all registered bars and restore cursor.
+fn finalize() {
+    let registry = REGISTRY.lock().unwrap_or_else(|x| x.into_inner());
+    let mut rows = 0;
+    let mut writer = Writer::Stderr;
+
+    for pb in registry.iter().filter_map(|x| x.upgrade()) {
+        let mut pb = pb.lock().unwrap_or_else(|x| x.into_inner());
+
+        if pb.get_leave() {
+            pb.refresh();
+            rows = rows.max(pb.get_position() + 1);
+            writer = pb.get_writer();
+        } else {
+            pb.clear();
+        }
+    }
+
+    writer.print(format_args!("\r{}\x1b[?25h", "\n".repeat(rows as usize)));
+}
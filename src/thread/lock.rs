@@ -1,8 +1,10 @@
 //! Thread safe sync between multiple bars.
 
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 
 static LOCKED: AtomicBool = AtomicBool::new(false);
+static POSITIONS: Mutex<Vec<bool>> = Mutex::new(vec![]);
 
 /// Wait until lock is free and then acquire it.
 pub fn acquire() {
@@ -18,3 +20,50 @@ pub fn acquire() {
 pub fn release() {
     LOCKED.store(false, Ordering::Release);
 }
+
+/// Position acquired from global registry of positions, which is released when dropped.
+#[derive(Debug)]
+pub(crate) struct PositionGuard(u16);
+
+impl PositionGuard {
+    /// Acquire next free position from global registry of positions.
+    pub(crate) fn acquire() -> Self {
+        Self::acquire_from(0)
+    }
+
+    /// Acquire next free position from global registry of positions, which is at least `min`.
+    pub(crate) fn acquire_from(min: u16) -> Self {
+        let mut positions = POSITIONS.lock().unwrap_or_else(|x| x.into_inner());
+        let min = min as usize;
+
+        if positions.len() < min {
+            positions.resize(min, false);
+        }
+
+        match positions.iter().skip(min).position(|x| !x) {
+            Some(position) => {
+                positions[min + position] = true;
+                Self((min + position) as u16)
+            }
+            None => {
+                positions.push(true);
+                Self((positions.len() - 1) as u16)
+            }
+        }
+    }
+
+    /// Returns acquired position.
+    pub(crate) fn position(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Drop for PositionGuard {
+    fn drop(&mut self) {
+        let mut positions = POSITIONS.lock().unwrap_or_else(|x| x.into_inner());
+
+        if let Some(acquired) = positions.get_mut(self.0 as usize) {
+            *acquired = false;
+        }
+    }
+}
@@ -1,13 +1,21 @@
-use crate::progress::{Bar, BarExt};
+use crate::progress::{Bar, BarExt, BarState};
+use crate::term::Colorizer;
 use std::collections::HashSet;
+use std::io::IsTerminal;
 
 /// RowManager allows to store and update many progress bars.
 ///
 /// `nrows` is the number of progress bars to display at once.
 /// All other bars are hidden and visible once any active progress bar is completed.
+/// Hidden bars are queued in [Pending](crate::BarState::Pending) state, so they are greyed out until they are updated.
 /// Traces of progress are left in terminal if `leave=true` else progress bar is cleared.
 /// Cursor position are not restored by RowManager.
 ///
+/// In compact mode, all progress bars are folded into a single line like
+/// `2/6 tasks | 47% | 120.00it/s | ETA 00:41`, which is useful over SSH or in terminals with few rows.
+/// Compact mode is enabled automatically if `nrows` is less than 2, terminal doesn't have more than `nrows` rows
+/// or stderr is not a terminal i.e. cursor positioning is impossible, see [set_compact](Self::set_compact) to override it.
+///
 /// # Example
 ///
 /// ```
@@ -20,7 +28,7 @@ use std::collections::HashSet;
 ///     manager.get_mut(pb_index).unwrap().update(1);
 ///     manager.notify(pb_index);
 /// }
-/// 
+///
 /// manager.bars.remove(pb_index);
 /// ```
 pub struct RowManager {
@@ -28,7 +36,11 @@ pub struct RowManager {
     avaliable_pos: HashSet<u16>,
     pub bars: Vec<Bar>,
     bars_true_disable: Vec<bool>,
+    compact: bool,
+    compact_length: usize,
+    compact_timer: Option<crate::Instant>,
     nrows: u16,
+    queue: (u16, Vec<String>),
 }
 
 impl RowManager {
@@ -48,7 +60,13 @@ impl RowManager {
             avaliable_pos: HashSet::new(),
             bars: vec![],
             bars_true_disable: vec![],
+            compact: nrows < 2
+                || crate::term::get_rows_or(u16::MAX) <= nrows
+                || !std::io::stderr().is_terminal(),
+            compact_length: 0,
+            compact_timer: None,
             nrows,
+            queue: (0, vec![]),
         }
     }
 
@@ -62,18 +80,66 @@ impl RowManager {
     /// let mut manager = RowManager::from_window_size();
     /// ```
     pub fn from_window_size() -> Self {
-        Self {
-            acquired_pos: HashSet::new(),
-            avaliable_pos: HashSet::new(),
+        Self::new(crate::term::get_rows_or(3).saturating_sub(2))
+    }
 
-            bars: vec![],
-            bars_true_disable: vec![],
-            nrows: terminal_size::terminal_size()
-                .unwrap_or((terminal_size::Width(0), terminal_size::Height(3)))
-                .1
-                 .0
-                - 2,
-        }
+    /// Create a scope for placing progress bars, which may be used from multiple threads.
+    /// Progress bars spawned using [spawn_bar](crate::RowScope::spawn_bar) acquire the lowest free row
+    /// and release it when dropped, so no manual `position` arithmetic is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, RowManager};
+    ///
+    /// RowManager::scoped(|scope| {
+    ///     std::thread::scope(|s| {
+    ///         for i in 0..3 {
+    ///             s.spawn(move || {
+    ///                 let mut pb = scope.spawn_bar(tqdm!(total = 100, desc = format!("worker {}", i)));
+    ///
+    ///                 for _ in 0..100 {
+    ///                     pb.update(1);
+    ///                 }
+    ///             });
+    ///         }
+    ///     });
+    ///
+    ///     assert_eq!(scope.acquired(), 0);
+    /// });
+    /// ```
+    pub fn scoped<F, T>(f: F) -> T
+    where
+        F: FnOnce(&RowScope) -> T,
+    {
+        let scope = RowScope {
+            acquired: std::sync::atomic::AtomicUsize::new(0),
+        };
+        f(&scope)
+    }
+
+    /// Enable or disable compact mode, in which all progress bars are folded into a single line.
+    /// This overrides automatic detection of compact mode done in [new](Self::new).
+    /// Compact mode should be set before any progress bar is appended or queued, since bars are
+    /// laid out according to it when they are added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::RowManager;
+    ///
+    /// let mut manager = RowManager::new(3);
+    /// // Always draw bars at separate rows, even if stderr is redirected.
+    /// manager.set_compact(false);
+    /// assert!(!manager.is_compact());
+    /// ```
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
+    /// Returns wheter compact mode is enabled or not.
+    pub fn is_compact(&self) -> bool {
+        self.compact
     }
 
     /// Returns the number of progress bars.
@@ -92,19 +158,68 @@ impl RowManager {
         pb.set_position(self.acquired_pos.len() as u16);
         self.bars_true_disable.push(pb.get_disable());
 
-        if self.nrows > pb.get_position() {
+        if self.compact {
+            pb.set_position(0);
+            pb.set_disable(true);
+        } else if self.nrows > pb.get_position() {
             pb.refresh();
             self.acquired_pos.insert(pb.get_position());
         } else {
             pb.set_disable(true);
+            pb.set_state(BarState::Pending);
         }
 
         self.bars.push(pb);
         self.bars.len() - 1
     }
 
+    /// Queue a not yet started progress bar returning its index.
+    ///
+    /// Queued bar is in [Pending](crate::BarState::Pending) state and it is displayed as a dimmed `waiting…` row
+    /// below active bars, if there are free rows. Like hidden bars, it is promoted to a free row on next notify
+    /// and it is greyed out until it is updated, so queue depth of thread pool workloads is visible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, BarExt, BarState, RowManager};
+    ///
+    /// let mut manager = RowManager::new(4);
+    /// let jobs = (0..3)
+    ///     .map(|i| manager.queue(tqdm!(total = 10, desc = format!("job {}", i))))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// for index in jobs {
+    ///     for _ in 0..10 {
+    ///         manager.get_mut(index).unwrap().update(1);
+    ///         manager.notify(index);
+    ///     }
+    ///
+    ///     assert_eq!(manager.get_mut(index).unwrap().get_state(), BarState::Done);
+    /// }
+    /// ```
+    pub fn queue(&mut self, mut pb: Bar) -> usize {
+        self.bars_true_disable.push(pb.get_disable());
+        // Queued bar is placed below visible rows like hidden bars, until it is promoted to a free row.
+        pb.set_position(self.nrows);
+        pb.set_state(BarState::Pending);
+        pb.set_disable(true);
+        self.bars.push(pb);
+
+        if !self.compact {
+            self.draw_queue();
+        }
+
+        self.bars.len() - 1
+    }
+
     /// Update and print the required stuff for progress bar at that index.
     pub fn notify(&mut self, index: usize) {
+        if self.compact {
+            self.notify_compact(index);
+            return;
+        }
+
         let pb = self.bars.get_mut(index).unwrap();
 
         if pb.completed() && !self.bars_true_disable.get(index).unwrap() {
@@ -135,8 +250,11 @@ impl RowManager {
             let mut count = 0;
             for (i, bar) in self.bars.iter_mut().enumerate() {
                 if bar.get_total() > bar.get_counter() && !self.bars_true_disable.get(i).unwrap() {
-                    if bar.get_position() != count {
-                        bar.clear();
+                    if bar.get_position() != count || bar.get_disable() {
+                        if !bar.get_disable() {
+                            bar.clear();
+                        }
+
                         bar.set_position(count);
                         bar.set_disable(false);
                         bar.refresh();
@@ -145,6 +263,8 @@ impl RowManager {
                     count += 1;
                 }
             }
+
+            self.draw_queue();
         } else {
             if self.nrows as usize == remaining_bars {
                 writer.print_at(
@@ -179,4 +299,250 @@ impl RowManager {
             }
         }
     }
+
+    /// Clear all displayed progress bars, run `f` which may print arbitrary output or launch an external program,
+    /// and then redraw progress bars. Returns output of `f`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kdam::{tqdm, RowManager};
+    ///
+    /// let mut manager = RowManager::new(3);
+    /// manager.append(tqdm!(total = 100));
+    /// manager.append(tqdm!(total = 100));
+    ///
+    /// manager.suspend(|| println!("bars are cleared"));
+    /// ```
+    pub fn suspend<F: FnOnce() -> R, R>(&mut self, f: F) -> R {
+        let writer = match self.bars.first() {
+            Some(pb) => pb.get_writer(),
+            None => return f(),
+        };
+
+        if self.compact {
+            writer.print(format_args!("\r{}\r", " ".repeat(self.compact_length)));
+            self.compact_timer = None;
+        } else {
+            for pb in self.bars.iter_mut().filter(|x| !x.get_disable()) {
+                pb.clear();
+            }
+
+            self.clear_queue();
+            writer.print_str("\r");
+        }
+
+        let output = f();
+
+        if self.compact {
+            self.notify_compact(0);
+        } else {
+            for pb in self.bars.iter_mut().filter(|x| !x.get_disable()) {
+                pb.refresh();
+            }
+
+            self.draw_queue();
+        }
+
+        output
+    }
+
+    /// Print queued progress bars as dimmed rows below active bars, as many as there are free rows.
+    /// Nothing is printed if rows are same as previously printed rows.
+    fn draw_queue(&mut self) {
+        let writer = match self.bars.first() {
+            Some(pb) => pb.get_writer(),
+            None => return,
+        };
+
+        let first_row = self.first_free_row();
+        let rows = self
+            .bars
+            .iter()
+            .zip(self.bars_true_disable.iter())
+            .filter(|(bar, true_disable)| {
+                !**true_disable && bar.get_disable() && bar.get_state() == BarState::Pending
+            })
+            .map(|(bar, _)| {
+                if bar.get_desc().is_empty() {
+                    "waiting…".colorize("dim")
+                } else {
+                    format!("{}: waiting…", bar.get_desc()).colorize("dim")
+                }
+            })
+            .take(self.nrows.saturating_sub(first_row) as usize)
+            .collect::<Vec<String>>();
+
+        if self.queue.0 == first_row && self.queue.1 == rows {
+            return;
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            writer.print_at(first_row as usize + i, format!("\r{}\x1b[K", row));
+        }
+
+        let (previous_first_row, previous_rows) = &self.queue;
+        let end = first_row + rows.len() as u16;
+
+        for row in end..(previous_first_row + previous_rows.len() as u16) {
+            writer.print_at(row as usize, "\r\x1b[K");
+        }
+
+        self.queue = (first_row, rows);
+    }
+
+    /// Clear rows of queued progress bars.
+    fn clear_queue(&mut self) {
+        let (first_row, rows) = std::mem::take(&mut self.queue);
+
+        if let Some(pb) = self.bars.first() {
+            let writer = pb.get_writer();
+
+            for row in self.first_free_row().max(first_row)..(first_row + rows.len() as u16) {
+                writer.print_at(row as usize, "\r\x1b[K");
+            }
+        }
+    }
+
+    /// Returns first row below active progress bars.
+    fn first_free_row(&self) -> u16 {
+        self.bars
+            .iter()
+            .filter(|x| !x.get_disable())
+            .map(|x| x.get_position() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Print all progress bars folded into a single line.
+    fn notify_compact(&mut self, index: usize) {
+        let mininterval = self.bars.get(index).unwrap().get_mininterval();
+        let mut bars = self
+            .bars
+            .iter_mut()
+            .zip(self.bars_true_disable.iter())
+            .filter(|(_, true_disable)| !**true_disable)
+            .map(|(bar, _)| bar)
+            .collect::<Vec<&mut Bar>>();
+
+        if bars.is_empty() {
+            return;
+        }
+
+        let completed = bars.iter().filter(|x| x.completed()).count();
+
+        if completed != bars.len() {
+            if let Some(timer) = self.compact_timer {
+                if timer.elapsed().as_secs_f32() < mininterval {
+                    return;
+                }
+            }
+        }
+
+        self.compact_timer = Some(crate::Instant::now());
+
+        let (mut counter, mut total, mut rate, mut elapsed_time) = (0, 0, 0.0, 0.0_f32);
+
+        for bar in bars.iter_mut() {
+            counter += bar.get_counter().min(bar.get_total());
+            total += bar.get_total();
+            let bar_elapsed_time = bar.elapsed_time();
+            elapsed_time = elapsed_time.max(bar_elapsed_time);
+
+            if bar_elapsed_time > 0.0 && !bar.completed() {
+                rate += bar.get_counter() as f32 / bar_elapsed_time;
+            }
+        }
+
+        // Average rate of all tasks, once every task is completed.
+        if completed == bars.len() && elapsed_time > 0.0 {
+            rate = counter as f32 / elapsed_time;
+        }
+
+        let percentage = if total == 0 {
+            100.0
+        } else {
+            (counter as f32 / total as f32 * 100.0).floor()
+        };
+
+        let text = format!(
+            "{}/{} tasks | {:.0}% | {} | ETA {}",
+            completed,
+            bars.len(),
+            percentage,
+            bars[0].fmt_rate_value(rate),
+            if counter == total {
+                crate::format::format_interval(0, false)
+            } else {
+                crate::format::format_eta((total - counter) as f32 / rate, false)
+            }
+        );
+
+        let length = text.len_ansi();
+        let writer = bars[0].get_writer();
+        writer.print(format_args!(
+            "\r{}{}",
+            text,
+            " ".repeat(self.compact_length.saturating_sub(length))
+        ));
+        self.compact_length = length;
+    }
+}
+
+/// Scope for placing progress bars, which may be used from multiple threads, see [RowManager::scoped](crate::RowManager::scoped).
+/// Unlike [RowManager](crate::RowManager), bars aren't owned by scope, so each thread renders its own bar.
+#[derive(Debug)]
+pub struct RowScope {
+    acquired: std::sync::atomic::AtomicUsize,
+}
+
+impl RowScope {
+    /// Place progress bar at the lowest free row and return a handle to it.
+    /// Rows are acquired from same global registry as [with_position_auto](crate::Bar::with_position_auto),
+    /// so scoped bars don't collide with other automatically placed bars.
+    /// Row is cleared and released when handle is dropped.
+    pub fn spawn_bar(&self, pb: Bar) -> ScopedBar<'_> {
+        self.acquired
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+        ScopedBar {
+            pb: pb.with_position_auto(),
+            scope: self,
+        }
+    }
+
+    /// Returns the number of rows acquired by bars of this scope.
+    pub fn acquired(&self) -> usize {
+        self.acquired.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+/// Progress bar handle tied to a [RowScope](crate::RowScope), see [RowScope::spawn_bar](crate::RowScope::spawn_bar).
+#[derive(Debug)]
+pub struct ScopedBar<'a> {
+    pb: Bar,
+    scope: &'a RowScope,
+}
+
+impl std::ops::Deref for ScopedBar<'_> {
+    type Target = Bar;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pb
+    }
+}
+
+impl std::ops::DerefMut for ScopedBar<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.pb
+    }
+}
+
+impl Drop for ScopedBar<'_> {
+    fn drop(&mut self) {
+        let _ = self.pb.try_clear();
+        self.scope
+            .acquired
+            .fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+    }
 }
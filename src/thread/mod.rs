@@ -1,6 +1,12 @@
+mod dashboard;
 mod manager;
 
+#[cfg(all(unix, feature = "signal"))]
+pub mod interrupt;
 pub mod lock;
 pub mod monitor;
+pub mod registry;
+pub mod ticker;
 
+pub use dashboard::*;
 pub use manager::*;
@@ -1,38 +1,176 @@
 //! Monitor mode for progress bars.
-//! 
+//!
 //! In monitor mode progress bar is refreshed in specific intervals.
 //! Default monitor modes may not fit in many cases.
 //! So it is recommended to create a custom monitor mode.
 //! The basic idea behind monitor mode is to create a separate thread for updating progress bar
 //! which can be achieved by following code.
-//! 
+//!
 //! ```
 //! use kdam::{Bar, BarExt};
 //! use std::sync::{Arc, Mutex};
 //! use std::thread;
-//! 
+//!
 //! fn custom_monitor(pb: Bar, maxinterval: f32) -> (Arc<Mutex<Bar>>, thread::JoinHandle<()>) {
 //!     let pb_arc = Arc::new(Mutex::new(pb));
 //!     let pb_arc_clone = pb_arc.clone();
-//! 
+//!
 //!     let handle = thread::spawn(move || loop {
 //!         thread::sleep(std::time::Duration::from_secs_f32(maxinterval));
 //!         let mut pb_monitor = pb_arc_clone.lock().unwrap();
-//! 
+//!
 //!         if pb_monitor.completed() {
 //!             break;
 //!         }
-//! 
+//!
 //!         pb_monitor.refresh();
 //!     });
-//! 
+//!
 //!     (pb_arc, handle)
 //! }
 //! ```
 
 use crate::progress::{Bar, BarExt, RichProgress};
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, Weak};
 use std::thread;
+use std::time::Duration;
+
+/// Handle of monitor thread, returned by [monitor::bar](crate::monitor::bar) and [monitor::rich](crate::monitor::rich).
+///
+/// Monitor thread exits when progress bar is completed, [stop](Self::stop) is called, cancellation flag is set or progress bar is dropped.
+/// Dropping handle detaches monitor thread.
+#[derive(Debug)]
+pub struct MonitorHandle {
+    handle: thread::JoinHandle<()>,
+    stopped: Arc<AtomicBool>,
+    /// Disconnected once monitor thread exits, since its sender is dropped.
+    exited: mpsc::Receiver<()>,
+}
+
+impl MonitorHandle {
+    /// Stop monitor thread without waiting for it to exit.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Release);
+        self.handle.thread().unpark();
+    }
+
+    /// Returns wheter monitor thread is finished or not.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Wait for monitor thread to exit for at most `timeout` duration.
+    /// Returns `true` if monitor thread exited within `timeout`, otherwise it is detached.
+    pub fn join(self, timeout: Duration) -> bool {
+        match self.exited.recv_timeout(timeout) {
+            Err(mpsc::RecvTimeoutError::Timeout) => false,
+            _ => self.handle.join().is_ok(),
+        }
+    }
+}
+
+/// Handle of hotkeys thread, returned by [monitor::hotkeys](crate::monitor::hotkeys).
+///
+/// Stopping or dropping handle waits for hotkeys thread to exit, so terminal mode is restored before it returns.
+/// Progress bar shouldn't be locked by current thread while stopping, since hotkeys thread may be waiting for it.
+#[derive(Debug)]
+pub struct HotkeysHandle {
+    handle: Option<thread::JoinHandle<()>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl HotkeysHandle {
+    /// Stop hotkeys thread and wait for it to restore terminal mode.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    /// Returns wheter hotkeys thread is finished or not.
+    pub fn is_finished(&self) -> bool {
+        self.handle.as_ref().is_none_or(|x| x.is_finished())
+    }
+
+    fn shutdown(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for HotkeysHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Spawn monitor thread which refreshes progress bar until `refresh` returns `false` or `cancel` flag is set.
+fn spawn<T: Send + 'static>(
+    pb: Weak<Mutex<T>>,
+    maxinterval: f32,
+    cancel: Option<Arc<AtomicBool>>,
+    refresh: fn(&mut T) -> bool,
+) -> MonitorHandle {
+    let stopped = Arc::new(AtomicBool::new(false));
+    let stopped_clone = stopped.clone();
+    let (exited_sender, exited) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        let _exited_sender: mpsc::Sender<()> = exited_sender;
+
+        loop {
+            thread::park_timeout(Duration::from_secs_f32(maxinterval));
+
+            if stopped_clone.load(Ordering::Acquire)
+                || cancel.as_ref().is_some_and(|x| x.load(Ordering::Acquire))
+            {
+                break;
+            }
+
+            let pb_arc = match pb.upgrade() {
+                Some(pb_arc) => pb_arc,
+                None => break,
+            };
+
+            let mut pb_monitor = match pb_arc.lock() {
+                Ok(pb_monitor) => pb_monitor,
+                Err(_) => break,
+            };
+
+            if !refresh(&mut pb_monitor) {
+                break;
+            }
+        }
+    });
+
+    MonitorHandle {
+        handle,
+        stopped,
+        exited,
+    }
+}
+
+/// Refresh [Bar](crate::Bar) in monitor mode, returns `false` once it is completed.
+fn refresh_bar(pb_monitor: &mut Bar) -> bool {
+    if pb_monitor.completed() {
+        return false;
+    }
+
+    pb_monitor.refresh();
+    true
+}
+
+/// Refresh [RichProgress](crate::RichProgress) in monitor mode, returns `false` once it is completed.
+fn refresh_rich(pb_monitor: &mut RichProgress) -> bool {
+    if pb_monitor.pb.completed() {
+        return false;
+    }
+
+    pb_monitor.refresh();
+    true
+}
 
 /// Monitor mode for [Bar](crate::Bar)
 ///
@@ -40,54 +178,268 @@ use std::thread;
 ///
 /// ```no_run
 /// use kdam::{tqdm, BarExt};
+/// use std::time::Duration;
 ///
 /// let pb = tqdm!(total = 100, force_refresh = true);
-/// let (pb_arc, monitor_thread) = kdam::monitor::bar(pb, 1.0);
+/// let (pb_arc, monitor) = kdam::monitor::bar(pb, 1.0);
 ///
 /// for _ in 0..100 {
 ///     pb_arc.lock().unwrap().update(1);
 ///     std::thread::sleep(std::time::Duration::from_secs_f32(3.0));
 /// }
 ///
-/// monitor_thread.join().unwrap();
+/// monitor.join(Duration::from_secs(2));
 /// eprint!("\n");
 /// ```
-pub fn bar(pb: Bar, maxinterval: f32) -> (Arc<Mutex<Bar>>, thread::JoinHandle<()>) {
+pub fn bar(pb: Bar, maxinterval: f32) -> (Arc<Mutex<Bar>>, MonitorHandle) {
     let pb_arc = Arc::new(Mutex::new(pb));
-    let pb_arc_clone = pb_arc.clone();
-
-    let handle = thread::spawn(move || loop {
-        thread::sleep(std::time::Duration::from_secs_f32(maxinterval));
-        let mut pb_monitor = pb_arc_clone.lock().unwrap();
-
-        if pb_monitor.completed() {
-            break;
-        }
+    let handle = spawn(Arc::downgrade(&pb_arc), maxinterval, None, refresh_bar);
+    (pb_arc, handle)
+}
 
-        pb_monitor.refresh();
-    });
+/// Monitor mode for [RichProgress](crate::RichProgress). See [monitor::bar](crate::monitor::bar) for example usecase.
+pub fn rich(pb: RichProgress, maxinterval: f32) -> (Arc<Mutex<RichProgress>>, MonitorHandle) {
+    let pb_arc = Arc::new(Mutex::new(pb));
+    let handle = spawn(Arc::downgrade(&pb_arc), maxinterval, None, refresh_rich);
+    (pb_arc, handle)
+}
 
+/// Monitor mode for [Bar](crate::Bar) which can be cancelled early, i.e. when job is aborted.
+/// Monitor thread exits at next refresh interval after `cancel` flag is set, without waiting for bar to complete.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+/// use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+/// use std::time::Duration;
+///
+/// let cancel = Arc::new(AtomicBool::new(false));
+/// let pb = tqdm!(total = 100, force_refresh = true);
+/// let (pb_arc, monitor) = kdam::monitor::bar_with_cancel(pb, 0.01, cancel.clone());
+///
+/// pb_arc.lock().unwrap().update(10);
+///
+/// // job is aborted
+/// cancel.store(true, Ordering::Release);
+/// assert!(monitor.join(Duration::from_secs(2)));
+/// eprint!("\n");
+/// ```
+pub fn bar_with_cancel(
+    pb: Bar,
+    maxinterval: f32,
+    cancel: Arc<AtomicBool>,
+) -> (Arc<Mutex<Bar>>, MonitorHandle) {
+    let pb_arc = Arc::new(Mutex::new(pb));
+    let handle = spawn(
+        Arc::downgrade(&pb_arc),
+        maxinterval,
+        Some(cancel),
+        refresh_bar,
+    );
     (pb_arc, handle)
 }
 
-/// Monitor mode for [RichProgress](crate::RichProgress). See [monitor::bar](crate::monitor::bar) for example usecase.
-pub fn rich(
+/// Monitor mode for [RichProgress](crate::RichProgress) which can be cancelled early.
+/// See [monitor::bar_with_cancel](crate::monitor::bar_with_cancel) for example usecase.
+pub fn rich_with_cancel(
     pb: RichProgress,
     maxinterval: f32,
-) -> (Arc<Mutex<RichProgress>>, thread::JoinHandle<()>) {
+    cancel: Arc<AtomicBool>,
+) -> (Arc<Mutex<RichProgress>>, MonitorHandle) {
     let pb_arc = Arc::new(Mutex::new(pb));
-    let pb_arc_clone = pb_arc.clone();
+    let handle = spawn(
+        Arc::downgrade(&pb_arc),
+        maxinterval,
+        Some(cancel),
+        refresh_rich,
+    );
+    (pb_arc, handle)
+}
 
-    let handle = thread::spawn(move || loop {
-        thread::sleep(std::time::Duration::from_secs_f32(maxinterval));
-        let mut pb_monitor = pb_arc_clone.lock().unwrap();
+/// Handle hotkeys of a shared [Bar](crate::Bar) for supervising long running jobs.
+///
+/// - `d`: toggle detailed stats, see [Bar::set_details](crate::Bar::set_details).
+/// - `q`: toggle hiding bar entirely.
+///
+/// Terminal is switched to [RawMode](crate::term::RawMode) while hotkeys are handled, keys are read using
+/// [poll_key](crate::term::poll_key). Hotkeys thread exits when bar is completed, [stop](HotkeysHandle::stop)
+/// is called or bar is dropped, or immediately if stdin is not a terminal. Terminal mode is restored by
+/// hotkeys thread before exiting, and [stop](HotkeysHandle::stop) waits for it.
+///
+/// # Example
+///
+/// ```no_run
+/// use kdam::{tqdm, BarExt};
+/// use std::sync::{Arc, Mutex};
+///
+/// let pb = Arc::new(Mutex::new(tqdm!(total = 100).with_history()));
+/// let hotkeys = kdam::monitor::hotkeys(&pb);
+///
+/// for _ in 0..100 {
+///     pb.lock().unwrap().update(1);
+///     std::thread::sleep(std::time::Duration::from_millis(100));
+/// }
+///
+/// hotkeys.stop();
+/// eprint!("\n");
+/// ```
+pub fn hotkeys(pb: &Arc<Mutex<Bar>>) -> HotkeysHandle {
+    let pb = Arc::downgrade(pb);
+    let stopped = Arc::new(AtomicBool::new(false));
+    let stopped_clone = stopped.clone();
 
-        if pb_monitor.pb.completed() {
-            break;
-        }
+    let handle = thread::spawn(move || {
+        let _raw = match crate::term::RawMode::enter() {
+            Ok(raw) => raw,
+            Err(_) => return,
+        };
+
+        while !stopped_clone.load(Ordering::Acquire) {
+            let key = crate::term::poll_key(Duration::from_millis(100));
 
-        pb_monitor.refresh();
+            let pb_arc = match pb.upgrade() {
+                Some(pb_arc) => pb_arc,
+                None => break,
+            };
+
+            let mut pb_hotkeys = match pb_arc.lock() {
+                Ok(pb_hotkeys) => pb_hotkeys,
+                Err(_) => break,
+            };
+
+            if pb_hotkeys.completed() {
+                break;
+            }
+
+            match key {
+                Some('d') => {
+                    let details = pb_hotkeys.get_details();
+                    pb_hotkeys.set_details(!details);
+                    pb_hotkeys.refresh();
+                }
+                Some('q') if pb_hotkeys.get_disable() => {
+                    pb_hotkeys.set_disable(false);
+                    pb_hotkeys.refresh();
+                }
+                Some('q') => {
+                    pb_hotkeys.clear();
+                    pb_hotkeys.set_disable(true);
+                }
+                _ => {}
+            }
+        }
     });
 
+    HotkeysHandle {
+        handle: Some(handle),
+        stopped,
+    }
+}
+
+/// Spawn tokio task which refreshes progress bar until `refresh` returns `false` or `cancel` token is cancelled.
+#[cfg(feature = "tokio")]
+fn spawn_tokio<T: Send + 'static, F: FnMut(&mut T) -> bool + Send + 'static>(
+    pb: Weak<Mutex<T>>,
+    maxinterval: f32,
+    cancel: tokio_util::sync::CancellationToken,
+    mut refresh: F,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let interval = Duration::from_secs_f32(maxinterval);
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(interval) => {}
+            }
+
+            let pb_arc = match pb.upgrade() {
+                Some(pb_arc) => pb_arc,
+                None => break,
+            };
+
+            let mut pb_monitor = match pb_arc.lock() {
+                Ok(pb_monitor) => pb_monitor,
+                Err(_) => break,
+            };
+
+            if !refresh(&mut pb_monitor) {
+                break;
+            }
+        }
+    })
+}
+
+/// Monitor mode for [Bar](crate::Bar) using a tokio task instead of a thread.
+/// Task exits when bar is completed, `cancel` token is cancelled or bar is dropped.
+/// Must be called from within a tokio runtime.
+///
+/// # Example
+///
+/// ```
+/// use kdam::{tqdm, BarExt};
+/// use tokio_util::sync::CancellationToken;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let cancel = CancellationToken::new();
+///     let pb = tqdm!(total = 100, force_refresh = true);
+///     let (pb_arc, monitor) = kdam::monitor::bar_async(pb, 0.1, cancel.clone());
+///
+///     for _ in 0..50 {
+///         pb_arc.lock().unwrap().update(1);
+///         tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+///     }
+///
+///     // job is aborted
+///     cancel.cancel();
+///     monitor.await.unwrap();
+///     eprint!("\n");
+/// }
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub fn bar_async(
+    pb: Bar,
+    maxinterval: f32,
+    cancel: tokio_util::sync::CancellationToken,
+) -> (Arc<Mutex<Bar>>, tokio::task::JoinHandle<()>) {
+    let pb_arc = Arc::new(Mutex::new(pb));
+    let handle = spawn_tokio(Arc::downgrade(&pb_arc), maxinterval, cancel, refresh_bar);
+    (pb_arc, handle)
+}
+
+/// Monitor mode for [Bar](crate::Bar) whose progress is counted into `counter` by another task (e.g. async IO adapters),
+/// so that bar is only rendered by monitor task and not by the task doing the work.
+#[cfg(feature = "tokio")]
+pub(crate) fn counter_async(
+    pb: &Arc<Mutex<Bar>>,
+    counter: Arc<std::sync::atomic::AtomicUsize>,
+    maxinterval: f32,
+    cancel: tokio_util::sync::CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    spawn_tokio(
+        Arc::downgrade(pb),
+        maxinterval,
+        cancel,
+        move |pb_monitor: &mut Bar| {
+            pb_monitor.set_counter(counter.load(Ordering::Acquire));
+            refresh_bar(pb_monitor)
+        },
+    )
+}
+
+/// Monitor mode for [RichProgress](crate::RichProgress) using a tokio task instead of a thread.
+/// See [monitor::bar_async](crate::monitor::bar_async) for example usecase.
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub fn rich_async(
+    pb: RichProgress,
+    maxinterval: f32,
+    cancel: tokio_util::sync::CancellationToken,
+) -> (Arc<Mutex<RichProgress>>, tokio::task::JoinHandle<()>) {
+    let pb_arc = Arc::new(Mutex::new(pb));
+    let handle = spawn_tokio(Arc::downgrade(&pb_arc), maxinterval, cancel, refresh_rich);
     (pb_arc, handle)
 }
@@ -0,0 +1,174 @@
+//! Named global progress bars for updating same bar from different modules.
+//!
+//! Bars are stored in a global registry by name, so different modules of an application
+//! can update same logical bar without passing references around.
+//!
+//! ```
+//! use kdam::BarExt;
+//!
+//! fn ingest() {
+//!     kdam::registry::get_or_create("ingest").lock().unwrap().update(1);
+//! }
+//!
+//! for _ in 0..10 {
+//!     ingest();
+//! }
+//!
+//! let pb = kdam::registry::get("ingest").unwrap();
+//! assert_eq!(pb.lock().unwrap().get_counter(), 10);
+//! assert!(kdam::registry::list().contains(&"ingest".to_owned()));
+//!
+//! kdam::registry::remove("ingest");
+//! eprint!("\n");
+//! ```
+
+use crate::progress::{Bar, BarExt};
+use std::sync::{Arc, Mutex};
+
+static REGISTRY: Mutex<Vec<(String, Arc<Mutex<Bar>>)>> = Mutex::new(vec![]);
+
+/// Returns shared handle of bar registered with `name`,
+/// a new bar with description `name` is created and registered if it doesn't exist.
+pub fn get_or_create(name: &str) -> Arc<Mutex<Bar>> {
+    get_or_create_with(name, || {
+        let mut pb = Bar::default();
+        pb.set_description(name);
+        pb
+    })
+}
+
+/// Returns shared handle of bar registered with `name`,
+/// bar returned by `init` is registered if it doesn't exist.
+///
+/// `init` is called without holding registry lock, so it can use registry itself.
+/// If another thread registers `name` meanwhile, bar registered by it is returned and bar returned by `init` is dropped.
+pub fn get_or_create_with<F: FnOnce() -> Bar>(name: &str, init: F) -> Arc<Mutex<Bar>> {
+    if let Some(pb) = get(name) {
+        return pb;
+    }
+
+    let pb = Arc::new(Mutex::new(init()));
+    let mut registry = REGISTRY.lock().unwrap_or_else(|x| x.into_inner());
+
+    if let Some((_, pb)) = registry.iter().find(|(x, _)| x == name) {
+        return pb.clone();
+    }
+
+    registry.push((name.to_owned(), pb.clone()));
+    pb
+}
+
+/// Returns shared handle of bar registered with `name`, if it exists.
+pub fn get(name: &str) -> Option<Arc<Mutex<Bar>>> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|x| x.into_inner())
+        .iter()
+        .find(|(x, _)| x == name)
+        .map(|(_, pb)| pb.clone())
+}
+
+/// Unregister bar with `name`, returns its shared handle if it was registered.
+pub fn remove(name: &str) -> Option<Arc<Mutex<Bar>>> {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|x| x.into_inner());
+    let index = registry.iter().position(|(x, _)| x == name)?;
+    Some(registry.remove(index).1)
+}
+
+/// Returns names of all registered bars, in order of registration.
+pub fn list() -> Vec<String> {
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|x| x.into_inner())
+        .iter()
+        .map(|(x, _)| x.clone())
+        .collect()
+}
+
+/// Refresh all registered bars.
+pub fn refresh_all() {
+    let bars = REGISTRY
+        .lock()
+        .unwrap_or_else(|x| x.into_inner())
+        .iter()
+        .map(|(_, pb)| pb.clone())
+        .collect::<Vec<_>>();
+
+    for pb in bars {
+        pb.lock().unwrap_or_else(|x| x.into_inner()).refresh();
+    }
+}
+
+/// Print a message without overlap with any registered bar, see [println](crate::registry::println).
+///
+/// Global lock is held while all registered bars are cleared, message is printed and bars are redrawn,
+/// so bars updated from other threads don't draw over message.
+pub fn group_write(args: std::fmt::Arguments) {
+    let bars = REGISTRY
+        .lock()
+        .unwrap_or_else(|x| x.into_inner())
+        .iter()
+        .map(|(_, pb)| pb.clone())
+        .collect::<Vec<_>>();
+
+    let mut bars = bars
+        .iter()
+        .map(|pb| pb.lock().unwrap_or_else(|x| x.into_inner()))
+        .collect::<Vec<_>>();
+
+    crate::thread::lock::acquire();
+
+    for pb in bars.iter_mut() {
+        let _ = pb.try_clear();
+    }
+
+    let writer = bars
+        .first()
+        .map_or(crate::term::Writer::Stderr, |pb| pb.get_writer());
+    let _ = writer.try_print(format_args!("\r{}\n", args));
+
+    for pb in bars.iter_mut() {
+        if pb.get_leave() {
+            pb.refresh();
+        }
+    }
+
+    crate::thread::lock::release();
+}
+
+/// Print a formatted message without overlap with any registered bar, like `println!`.
+///
+/// Unlike [bar_println](crate::bar_println), which only coordinates with a single bar,
+/// all bars of global [registry](crate::registry) are cleared and redrawn.
+///
+/// Macro is only exported from `registry` module, so that glob imports of crate (`use kdam::*;`)
+/// don't shadow `println!` of standard library.
+///
+/// # Example
+///
+/// ```
+/// use kdam::BarExt;
+///
+/// let pb = kdam::registry::get_or_create("download");
+///
+/// for i in 0..10 {
+///     pb.lock().unwrap().update(1);
+///     kdam::registry::println!("downloaded chunk {}", i);
+/// }
+///
+/// kdam::registry::remove("download");
+/// eprint!("\n");
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __println {
+    () => {
+        $crate::registry::group_write(format_args!(""))
+    };
+    ($($arg: tt)*) => {
+        $crate::registry::group_write(format_args!($($arg)*))
+    };
+}
+
+#[doc(inline)]
+pub use crate::__println as println;
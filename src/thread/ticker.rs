@@ -0,0 +1,136 @@
+//! Refresh scheduling of many progress bars via a global ticker thread.
+//!
+//! Bars registered with the ticker are repainted by a single background thread at a fixed fps.
+//! Updating a registered bar only increments an atomic counter, so the render cost
+//! doesn't grow with the number of updates, which is useful when many bars are updated very frequently.
+//!
+//! ```
+//! use kdam::tqdm;
+//!
+//! let pb = kdam::ticker::register(tqdm!(total = 100));
+//!
+//! for _ in 0..100 {
+//!     pb.update(1);
+//! }
+//!
+//! drop(pb);
+//! eprint!("\n");
+//! ```
+
+use crate::progress::{Bar, BarExt};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::thread;
+
+static FPS: AtomicU32 = AtomicU32::new(10);
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+    bars: vec![],
+    running: false,
+});
+
+struct Registry {
+    bars: Vec<Weak<Shared>>,
+    running: bool,
+}
+
+struct Shared {
+    counter: AtomicUsize,
+    pb: Mutex<Bar>,
+}
+
+impl Shared {
+    fn sync(&self) {
+        let mut pb = self.pb.lock().unwrap();
+        pb.set_counter(self.counter.load(Ordering::Acquire));
+        pb.refresh();
+    }
+}
+
+/// Set number of frames per second at which registered bars are repainted.
+/// (default: `10`)
+pub fn set_fps(fps: u32) {
+    FPS.store(fps.max(1), Ordering::SeqCst);
+}
+
+/// Register a progress bar with global ticker, starting ticker thread if it isn't running.
+pub fn register(pb: Bar) -> TickerBar {
+    let shared = Arc::new(Shared {
+        counter: AtomicUsize::new(pb.get_counter()),
+        pb: Mutex::new(pb),
+    });
+
+    let mut registry = REGISTRY.lock().unwrap();
+    registry.bars.push(Arc::downgrade(&shared));
+
+    if !registry.running {
+        registry.running = true;
+        thread::spawn(tick);
+    }
+
+    TickerBar { shared }
+}
+
+fn tick() {
+    loop {
+        thread::sleep(std::time::Duration::from_secs_f32(
+            1.0 / FPS.load(Ordering::Acquire) as f32,
+        ));
+
+        let bars = {
+            let mut registry = REGISTRY.lock().unwrap();
+            registry.bars.retain(|x| x.strong_count() > 0);
+
+            if registry.bars.is_empty() {
+                registry.running = false;
+                break;
+            }
+
+            registry
+                .bars
+                .iter()
+                .filter_map(|x| x.upgrade())
+                .collect::<Vec<_>>()
+        };
+
+        // Registry is unlocked before syncing, so registering bars doesn't wait for terminal IO.
+        for shared in bars {
+            shared.sync();
+        }
+    }
+}
+
+/// Handle of a progress bar registered with global ticker.
+///
+/// Bar is repainted one last time and unregistered when handle is dropped.
+pub struct TickerBar {
+    shared: Arc<Shared>,
+}
+
+impl TickerBar {
+    /// Increment counter of progress bar, display is updated by ticker thread.
+    pub fn update(&self, n: usize) {
+        self.shared.counter.fetch_add(n, Ordering::AcqRel);
+    }
+
+    /// Set counter of progress bar, display is updated by ticker thread.
+    pub fn update_to(&self, update_to_n: usize) {
+        self.shared.counter.store(update_to_n, Ordering::Release);
+    }
+
+    /// Returns current counter value.
+    pub fn get_counter(&self) -> usize {
+        self.shared.counter.load(Ordering::Acquire)
+    }
+
+    /// Lock inner progress bar for modifying its properties.
+    /// Counter of inner bar is synced only at each tick, use `self.get_counter` instead.
+    pub fn lock(&self) -> MutexGuard<'_, Bar> {
+        self.shared.pb.lock().unwrap()
+    }
+}
+
+impl Drop for TickerBar {
+    fn drop(&mut self) {
+        self.shared.sync();
+    }
+}